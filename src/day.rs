@@ -1,8 +1,27 @@
+use std::env;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// The number of days of the events up to 2024.
+pub const MAX_DAYS: u8 = 25;
+
+/// Returns the number of days of an event, events have 12 days starting in 2025.
+pub const fn days_in_year(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        MAX_DAYS
+    }
+}
+
+/// Returns the number of days of the year configured in `AOC_YEAR`.
+/// If no valid year is configured, all 25 days are allowed.
+pub fn last_day() -> u8 {
+    Year::from_env().ok().flatten().map_or(MAX_DAYS, Year::days)
+}
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25, or 1 to 12 from 2025 on).
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -16,10 +35,16 @@ use std::str::FromStr;
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// Creates a [`Day`] from the provided value if it's in the valid range of the configured year,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        Self::new_in_year(day, last_day())
+    }
+
+    /// Creates a [`Day`] from the provided value if it's in the range 1 to `last_day`,
+    /// returns [`None`] otherwise.
+    pub fn new_in_year(day: u8, last_day: u8) -> Option<Self> {
+        if day == 0 || day > last_day {
             return None;
         }
         Some(Self(day))
@@ -61,39 +86,181 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let last_day = last_day();
+        let err = DayFromStrError { last_day };
+        let day = s.parse().map_err(|_| err)?;
+        Self::new_in_year(day, last_day).ok_or(err)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    last_day: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.last_day)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// The year of the first event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year: Year = "2023".parse().unwrap();
+/// assert_eq!(year.to_string(), "2023");
+/// assert_eq!(year.days(), 25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's 2015 or later,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the year from `AOC_YEAR`, returns [`None`] if it is not set.
+    pub fn from_env() -> Result<Option<Self>, YearFromStrError> {
+        match env::var("AOC_YEAR") {
+            Ok(year) if !year.trim().is_empty() => year.trim().parse().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of days of the event.
+    pub fn days(self) -> u8 {
+        days_in_year(self.0)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug, Clone, Copy)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `year/day`.
+///
+/// ```
+/// # use advent_of_code::{day, year, PuzzleId};
+/// let id = PuzzleId::new(year!(2023), day!(8)).unwrap();
+/// assert_eq!(id.to_string(), "2023/08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the event of the year has the day,
+    /// returns [`None`] otherwise.
+    pub fn new(year: Year, day: Day) -> Option<Self> {
+        if day > year.days() {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Parses the day of a puzzle in a year, e.g. a day passed on the command-line.
+    pub fn parse_day(year: Year, day: &str) -> Result<Self, DayFromStrError> {
+        let err = DayFromStrError {
+            last_day: year.days(),
+        };
+        let day = day.parse().map_err(|_| err)?;
+        let day = Day::new_in_year(day, year.days()).ok_or(err)?;
+        Ok(Self { year, day })
+    }
+
+    /// The name of the solution binary, e.g. `2023_01`.
+    pub fn bin_name(&self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every day of advent of the configured year, from the 1st to the last day.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day.
 pub struct AllDays {
     current: u8,
+    last_day: u8,
 }
 
 impl AllDays {
+    /// Yields the days of the year configured in `AOC_YEAR`.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::in_year(last_day())
+    }
+
+    /// Yields the days from the 1st to `last_day`.
+    pub fn in_year(last_day: u8) -> Self {
+        Self {
+            current: 1,
+            last_day,
+        }
     }
 }
 
@@ -101,10 +268,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last_day {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -114,12 +281,125 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A range of days, such as `1-5`, a single day such as `7`, or an open range such as `20-`.
+/// An open range includes all days from its start to the last day of the event.
+///
+/// ```
+/// # use advent_of_code::{day, DayRange};
+/// let range: DayRange = "20-".parse().unwrap();
+/// assert!(range.contains(day!(25)));
+/// assert!(!range.contains(day!(19)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    start: Day,
+    end: Option<Day>,
+}
+
+impl DayRange {
+    /// Whether the range includes the day.
+    pub fn contains(&self, day: Day) -> bool {
+        day >= self.start && self.end.is_none_or(|end| day <= end)
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DayRangeFromStrError {
+            range: s.to_string(),
+        };
+        // ranges are not limited to the days of a year, so they can be used for several years.
+        let parse_day = |day: &str| {
+            let day = day.trim().parse().map_err(|_| err())?;
+            Day::new_in_year(day, MAX_DAYS).ok_or_else(err)
+        };
+
+        let range = match s.split_once('-') {
+            Some((start, end)) if end.trim().is_empty() => Self {
+                start: parse_day(start)?,
+                end: None,
+            },
+            Some((start, end)) => Self {
+                start: parse_day(start)?,
+                end: Some(parse_day(end)?),
+            },
+            None => {
+                let day = parse_day(s)?;
+                Self {
+                    start: day,
+                    end: Some(day),
+                }
+            }
+        };
+
+        if range.end.is_some_and(|end| end < range.start) {
+            return Err(err());
+        }
+
+        Ok(range)
+    }
+}
+
+/// A set of days, given as comma-separated [`DayRange`]s such as `1-5,7,20-`.
+///
+/// ```
+/// # use advent_of_code::{day, DaySet};
+/// let days: DaySet = "1-5,7,20-".parse().unwrap();
+/// assert!(days.contains(day!(3)));
+/// assert!(days.contains(day!(7)));
+/// assert!(!days.contains(day!(8)));
+/// assert!(days.contains(day!(21)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(Vec<DayRange>);
+
+impl DaySet {
+    /// Whether any of the ranges includes the day.
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.iter().any(|range| range.contains(day))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`] or a [`DaySet`].
+#[derive(Debug, Clone)]
+pub struct DayRangeFromStrError {
+    range: String,
+}
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day range `{}`, expecting days between 1 and {MAX_DAYS} such as `1-5,7,20-`",
+            self.range
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
+/// The day is checked against the days of the longest events, use [`puzzle!`] to check it against a year.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $crate::MAX_DAYS,
             concat!(
                 "invalid day number `",
                 $day,
@@ -130,15 +410,53 @@ macro_rules! day {
     }};
 }
 
+/// Creates a [`PuzzleId`] value in a const context.
+/// The day is checked against the number of days of the year.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::FIRST_YEAR && $day != 0 && $day <= $crate::days_in_year($year),
+            concat!(
+                "invalid puzzle `",
+                $year,
+                "/",
+                $day,
+                "`, expecting a year of 2015 or later and a day of its event"
+            ),
+        );
+        $crate::PuzzleId {
+            year: $crate::Year::__new_unchecked($year),
+            day: $crate::Day::__new_unchecked($day),
+        }
+    }};
+}
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::FIRST_YEAR,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{days_in_year, AllDays, Day, DayRange, DaySet, PuzzleId, Year};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::in_year(days_in_year(2023));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +485,100 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn days_of_twelve_day_events() {
+        assert_eq!(AllDays::in_year(days_in_year(2025)).count(), 12);
+        assert_eq!(AllDays::in_year(days_in_year(2025)).last(), Some(Day(12)));
+        assert_eq!(Day::new_in_year(13, days_in_year(2025)), None);
+        assert_eq!(Day::new_in_year(13, days_in_year(2024)), Some(Day(13)));
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2015".parse::<Year>().ok(), Some(Year(2015)));
+        assert_eq!(" 2023".parse::<Year>().ok(), None);
+        assert_eq!("2014".parse::<Year>().ok(), None);
+        assert_eq!("23".parse::<Year>().ok(), None);
+        assert_eq!(
+            "20x3".parse::<Year>().unwrap_err().to_string(),
+            "expecting a year of 2015 or later"
+        );
+    }
+
+    #[test]
+    fn parses_puzzle_days() {
+        let id = PuzzleId::parse_day(Year(2025), "12").unwrap();
+        assert_eq!(id.bin_name(), "2025_12");
+        assert_eq!(
+            PuzzleId::parse_day(Year(2025), "13")
+                .unwrap_err()
+                .to_string(),
+            "expecting a day number between 1 and 12"
+        );
+        assert!(PuzzleId::parse_day(Year(2024), "13").is_ok());
+        assert!(PuzzleId::parse_day(Year(2024), "x").is_err());
+    }
+
+    #[test]
+    fn puzzle_ids() {
+        assert_eq!(
+            PuzzleId::new(Year(2024), Day(25)).unwrap().to_string(),
+            "2024/25"
+        );
+        assert_eq!(
+            PuzzleId::new(Year(2025), Day(12)).unwrap().to_string(),
+            "2025/12"
+        );
+        assert_eq!(PuzzleId::new(Year(2025), Day(13)), None);
+    }
+
+    #[test]
+    fn parses_day_ranges() {
+        let range = |s: &str| s.parse::<DayRange>().ok();
+        assert_eq!(
+            range("1-5"),
+            Some(DayRange {
+                start: Day(1),
+                end: Some(Day(5))
+            })
+        );
+        assert_eq!(
+            range("7"),
+            Some(DayRange {
+                start: Day(7),
+                end: Some(Day(7))
+            })
+        );
+        assert_eq!(
+            range("20-"),
+            Some(DayRange {
+                start: Day(20),
+                end: None
+            })
+        );
+        assert_eq!(range("5-1"), None);
+        assert_eq!(range("0-3"), None);
+        assert_eq!(range("1-26"), None);
+        assert_eq!(range("-5"), None);
+        assert_eq!(range(""), None);
+    }
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "1-3, 7,20-".parse().unwrap();
+        let included: Vec<u8> = AllDays::in_year(25)
+            .filter(|&day| days.contains(day))
+            .map(Day::into_inner)
+            .collect();
+        assert_eq!(included, vec![1, 2, 3, 7, 20, 21, 22, 23, 24, 25]);
+
+        assert_eq!(
+            "1-3,x".parse::<DaySet>().unwrap_err().to_string(),
+            "invalid day range `x`, expecting days between 1 and 25 such as `1-5,7,20-`"
+        );
+        assert!("1-3,".parse::<DaySet>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,4 +1,6 @@
 mod day;
+mod part;
 pub mod template;
 
 pub use day::*;
+pub use part::*;
@@ -1,56 +1,150 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{all, download, example, read, scaffold, session, solve};
 use args::{parse, AppArguments};
 
 mod args {
-    use std::process;
+    use std::{process, time::Duration};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{
+            aoc_client,
+            commands::{
+                all::{solution_years, DaySelection},
+                session::SessionCommand,
+            },
+            runner::{arg_or_env, parse_duration, parse_timeout, BenchConfig, SubmitConfig},
+            InputSource,
+        },
+        Part, PuzzleId, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+            wait: bool,
+        },
+        Example {
+            puzzle: PuzzleId,
+            write: bool,
+            force: bool,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Session {
+            command: SessionCommand,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             time: bool,
-            submit: Option<u8>,
+            submit: Option<SubmitConfig>,
+            bench: BenchConfig,
+            timeout: Option<Duration>,
+            input: InputSource,
+            part: Option<Part>,
         },
         All {
+            selection: DaySelection,
             release: bool,
             time: bool,
+            bench: BenchConfig,
+            timeout: Option<Duration>,
+            day_timeout: Option<Duration>,
+            part: Option<Part>,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        // a typo in the year would otherwise go unnoticed, as all 25 days are valid without a year.
+        Year::from_env().map_err(|e| format!("invalid AOC_YEAR: {e}"))?;
+
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                selection: DaySelection {
+                    years: parse_years(&mut args)?,
+                    days: args.opt_value_from_str("--days")?,
+                    skip: args.opt_value_from_str("--skip")?,
+                    only_unsolved_answers: args.contains("--only-unsolved-answers"),
+                },
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                bench: BenchConfig::parse(&mut args)?,
+                timeout: parse_timeout(&mut args)?,
+                day_timeout: arg_or_env(
+                    &mut args,
+                    "--day-timeout",
+                    "AOC_DAY_TIMEOUT",
+                    parse_duration,
+                )?,
+                part: args.opt_value_from_str("--part")?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
+                wait: args.contains("--wait"),
+            },
+            Some("example") => AppArguments::Example {
+                puzzle: parse_puzzle(&mut args)?,
+                write: args.contains("--write"),
+                force: args.contains("--force"),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+            Some("session") => AppArguments::Session {
+                command: match args.subcommand()?.as_deref() {
+                    Some("set") => SessionCommand::Set {
+                        project: args.contains("--project"),
+                        cookie: args.opt_free_from_str()?,
+                    },
+                    Some("show") => SessionCommand::Show,
+                    Some("check") => SessionCommand::Check,
+                    _ => {
+                        eprintln!("Usage: cargo session set [<cookie>] [--project] | show | check");
+                        process::exit(1);
+                    }
+                },
             },
+            Some("solve") => {
+                let puzzle = parse_puzzle(&mut args)?;
+                let submit = SubmitConfig::parse(&mut args)?;
+
+                if let Some(submit) = submit.filter(|submit| !submit.part.has_puzzle(puzzle)) {
+                    return Err(format!(
+                        "day {} has no puzzle for part {}, its star is awarded for all others",
+                        puzzle.day, submit.part
+                    )
+                    .into());
+                }
+
+                let part: Option<Part> = args.opt_value_from_str("--part")?;
+
+                if let Some((submit, part)) = submit.zip(part).filter(|(s, p)| s.part != *p) {
+                    return Err(format!(
+                        "part {} is not run with `--part {part}`, so it can not be submitted",
+                        submit.part
+                    )
+                    .into());
+                }
+
+                AppArguments::Solve {
+                    puzzle,
+                    release: args.contains("--release"),
+                    submit,
+                    time: args.contains("--time"),
+                    bench: BenchConfig::parse(&mut args)?,
+                    timeout: parse_timeout(&mut args)?,
+                    input: InputSource::parse(&mut args)?,
+                    part,
+                }
+            }
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -68,6 +162,33 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses the day that follows a subcommand, in the year passed with `--year` or the default year.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let day: String = args.free_from_str()?;
+        let year = parse_year(args)?;
+        Ok(PuzzleId::parse_day(year, &day)?)
+    }
+
+    /// Parses the years to run: every year with solutions with `--all-years`, otherwise a single year.
+    fn parse_years(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Vec<Year>, Box<dyn std::error::Error>> {
+        if args.contains("--all-years") {
+            return Ok(solution_years());
+        }
+        Ok(vec![parse_year(args)?])
+    }
+
+    /// Parses `--year`, falling back to `AOC_YEAR` and then the most recent event.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Ok(aoc_client::default_year().map_err(|e| e.to_string())?),
+        }
+    }
 }
 
 fn main() {
@@ -77,16 +198,50 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                selection,
+                release,
+                time,
+                bench,
+                timeout,
+                day_timeout,
+                part,
+            } => all::handle(
+                &selection,
+                release,
+                time,
+                &bench,
+                timeout,
+                day_timeout,
+                part,
+            ),
+            AppArguments::Download { puzzle, wait } => download::handle(puzzle, wait),
+            AppArguments::Example {
+                puzzle,
+                write,
+                force,
+            } => example::handle(puzzle, write, force),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle } => scaffold::handle(puzzle),
+            AppArguments::Session { command } => session::handle(command),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                bench,
+                timeout,
+                input,
+                part,
+            } => solve::handle(
+                puzzle,
+                release,
+                submit,
+                time.then_some(&bench),
+                timeout,
+                &input,
+                part,
+            ),
         },
     };
 }
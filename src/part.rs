@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::PuzzleId;
+
+/// A part of a puzzle, every day has two.
+///
+/// # Display
+/// This value displays as its number.
+///
+/// ```
+/// # use advent_of_code::Part;
+/// let part: Part = "2".parse().unwrap();
+/// assert_eq!(part, Part::Two);
+/// assert_eq!(part.to_string(), "2")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Creates a [`Part`] from its number, returns [`None`] if it is not 1 or 2.
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    /// Converts the [`Part`] into its number.
+    pub fn into_inner(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    /// Whether the part has a puzzle to solve.
+    /// The last day of an event only has one puzzle, its second star is awarded for collecting all other stars.
+    pub fn has_puzzle(self, id: PuzzleId) -> bool {
+        self == Self::One || id.day < id.year.days()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+impl PartialEq<u8> for Part {
+    fn eq(&self, other: &u8) -> bool {
+        self.into_inner().eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part = s.parse().map_err(|_| PartFromStrError)?;
+        Self::new(part).ok_or(PartFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug, Clone, Copy)]
+pub struct PartFromStrError;
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a part number of 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Part;
+    use crate::puzzle;
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("1".parse::<Part>().ok(), Some(Part::One));
+        assert_eq!("2".parse::<Part>().ok(), Some(Part::Two));
+        assert!("0".parse::<Part>().is_err());
+        assert!("3".parse::<Part>().is_err());
+        assert!("one".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn last_day_has_one_puzzle() {
+        assert!(Part::One.has_puzzle(puzzle!(2023, 25)));
+        assert!(!Part::Two.has_puzzle(puzzle!(2023, 25)));
+        assert!(!Part::Two.has_puzzle(puzzle!(2025, 12)));
+        assert!(Part::Two.has_puzzle(puzzle!(2025, 11)));
+        assert!(Part::Two.has_puzzle(puzzle!(2023, 1)));
+    }
+}
@@ -0,0 +1,161 @@
+/// Heap allocation accounting for solution parts.
+/// With the `alloc_stats` feature enabled, a counting global allocator is installed for all binaries using this library.
+#[cfg(feature = "alloc_stats")]
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Heap allocations made while running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub count: usize,
+    /// Total number of bytes allocated.
+    pub bytes: usize,
+    /// Highest number of bytes that were allocated at the same time, relative to the start of the measurement.
+    pub peak_bytes: usize,
+}
+
+/// Runs `func` and returns the allocations it made.
+/// Returns [`None`] for the allocations if the `alloc_stats` feature is disabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc_stats")]
+    {
+        let count = COUNT.load(Ordering::SeqCst);
+        let bytes = BYTES.load(Ordering::SeqCst);
+        let live = LIVE.load(Ordering::SeqCst);
+        PEAK.store(live, Ordering::SeqCst);
+
+        let result = func();
+
+        let stats = AllocStats {
+            count: COUNT.load(Ordering::SeqCst) - count,
+            bytes: BYTES.load(Ordering::SeqCst) - bytes,
+            peak_bytes: PEAK.load(Ordering::SeqCst).saturating_sub(live),
+        };
+
+        (result, Some(stats))
+    }
+
+    #[cfg(not(feature = "alloc_stats"))]
+    {
+        (func(), None)
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "alloc_stats")]
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc_stats")]
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc_stats")]
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc_stats")]
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that forwards to the system allocator while counting allocations.
+#[cfg(feature = "alloc_stats")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc_stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[cfg(feature = "alloc_stats")]
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc_stats")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a reallocation counts as freeing the old block and allocating the new one.
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc_stats")]
+    fn counts_allocations() {
+        let (len, stats) = measure(|| vec![0_u8; 4096].len());
+        let stats = stats.unwrap();
+        assert_eq!(len, 4096);
+        assert!(stats.count >= 1);
+        assert!(stats.bytes >= 4096);
+        assert!(stats.peak_bytes >= 4096);
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc_stats"))]
+    fn disabled_without_feature() {
+        assert_eq!(measure(|| 1), (1, None));
+    }
+}
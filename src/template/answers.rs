@@ -0,0 +1,207 @@
+/// Module that reads confirmed answers from `data/YYYY/answers/DD.toml` and checks results against them.
+/// The file is a minimal subset of TOML:
+///
+/// ```toml
+/// part_one = "42"
+/// part_two = 1337
+/// ```
+use std::{env, fs, io};
+
+use crate::template::get_data_path;
+use crate::{Part, PuzzleId};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Outcome of comparing a result against the confirmed answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Correct,
+    Wrong,
+    /// There is no confirmed answer for this part yet.
+    Unknown,
+}
+
+impl AnswerCheck {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::str::FromStr for AnswerCheck {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "wrong" => Ok(Self::Wrong),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Confirmed answers for a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers for a day, a missing file yields empty answers.
+    pub fn load(id: PuzzleId) -> Result<Self, Error> {
+        let path = env::current_dir()?.join(get_path(id));
+
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::Parser(format!("line {}: expecting `key = value`", i + 1)))?;
+
+            let value = parse_value(value.trim())
+                .ok_or_else(|| Error::Parser(format!("line {}: invalid value", i + 1)))?;
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value),
+                "part_two" => answers.part_two = Some(value),
+                key => {
+                    return Err(Error::Parser(format!(
+                        "line {}: unknown key `{key}`",
+                        i + 1
+                    )))
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Returns the confirmed answer for a part.
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    /// Compares a result against the confirmed answer for a part.
+    #[must_use]
+    pub fn check(&self, part: Part, result: Option<&str>) -> AnswerCheck {
+        match (self.get(part), result) {
+            (None, _) => AnswerCheck::Unknown,
+            (Some(expected), Some(result)) if expected == result.trim() => AnswerCheck::Correct,
+            (Some(_), _) => AnswerCheck::Wrong,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(id: PuzzleId) -> String {
+    get_data_path("answers", id, ".toml")
+}
+
+/// Parses a quoted string (with `\"` and `\\` escapes) or a bare value such as a number.
+fn parse_value(s: &str) -> Option<String> {
+    let Some(quoted) = s.strip_prefix('"') else {
+        let bare = s.split('#').next()?.trim();
+        return (!bare.is_empty()).then(|| bare.to_string());
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(chars.next()?),
+            '"' => {
+                let rest = chars.as_str().trim();
+                return (rest.is_empty() || rest.starts_with('#')).then_some(value);
+            }
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AnswerCheck, Answers};
+    use crate::Part;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse(
+            "# confirmed answers\npart_one = \"abc\\\"def\" # comment\n\npart_two = 1337\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(Part::One), Some("abc\"def"));
+        assert_eq!(answers.get(Part::Two), Some("1337"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::parse("part_one = 42").unwrap();
+        assert_eq!(answers.get(Part::One), Some("42"));
+        assert_eq!(answers.get(Part::Two), None);
+    }
+
+    #[test]
+    fn rejects_invalid_answers() {
+        assert!(Answers::parse("part_three = 1").is_err());
+        assert!(Answers::parse("part_one 1").is_err());
+        assert!(Answers::parse("part_one = \"unterminated").is_err());
+        assert!(Answers::parse("part_one =").is_err());
+    }
+
+    #[test]
+    fn checks_results() {
+        let answers = Answers::parse("part_one = 42").unwrap();
+        assert_eq!(answers.check(Part::One, Some("42")), AnswerCheck::Correct);
+        assert_eq!(answers.check(Part::One, Some("43")), AnswerCheck::Wrong);
+        assert_eq!(answers.check(Part::One, None), AnswerCheck::Wrong);
+        assert_eq!(answers.check(Part::Two, Some("42")), AnswerCheck::Unknown);
+    }
+}
@@ -0,0 +1,682 @@
+/// Module that talks to the Advent of Code website to download inputs and puzzles and to submit answers.
+/// Requests are authenticated with the session cookie, see [`crate::template::session`].
+use std::{
+    env,
+    fmt::Display,
+    io,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::runner::{countdown, format_remaining};
+use crate::template::{get_data_path, html, puzzle, session, write_file};
+use crate::{day, year, Day, Part, PuzzleId, Year, YearFromStrError};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie is configured.
+    SessionNotFound,
+    /// The server rejected the session cookie, it has most likely expired.
+    SessionExpired,
+    /// The server responded with an unexpected status code.
+    BadStatus(u16, String),
+    /// The request could not be sent, e.g. because the network is down.
+    Transport(String),
+    /// The puzzle unlocks after this much time.
+    NotUnlocked(Duration),
+    /// `AOC_YEAR` is not a valid year.
+    InvalidYear(YearFromStrError),
+    IoError(io::Error),
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Copy the `session` cookie of adventofcode.com and run `cargo session set <cookie>`."
+            ),
+            AocClientError::SessionExpired => write!(
+                f,
+                "the session cookie was rejected, it has probably expired. Log in to adventofcode.com again and run `cargo session set <cookie>` with the new cookie."
+            ),
+            AocClientError::BadStatus(status, body) => {
+                write!(f, "server responded with status {status}: {}", body.trim())
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::InvalidYear(e) => write!(f, "invalid AOC_YEAR: {e}."),
+            AocClientError::NotUnlocked(remaining) => write!(
+                f,
+                "the puzzle unlocks in {}. Pass `--wait` to download it as soon as it unlocks.",
+                format_remaining(*remaining)
+            ),
+            AocClientError::IoError(e) => write!(f, "could not access file system: {e}"),
+        }
+    }
+}
+
+/// Outcome of submitting an answer, classified from the response message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is wrong and higher than the correct answer.
+    TooHigh,
+    /// The answer is wrong and lower than the correct answer.
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Incorrect,
+    /// The part was already solved, or part one has to be solved first.
+    AlreadyCompleted,
+    /// An answer was submitted too recently.
+    PleaseWait,
+    /// The response message could not be classified.
+    Unknown,
+}
+
+impl Verdict {
+    /// Classifies the message of a submission response.
+    #[must_use]
+    pub fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Self::TooHigh
+            } else if message.contains("too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Self::PleaseWait
+        } else if message.contains("Did you already complete it") {
+            Self::AlreadyCompleted
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Exit code of `solve --submit` for this verdict, so scripts can tell verdicts apart.
+    /// `1` is reserved for errors that prevented the submission.
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Correct => 0,
+            Self::Incorrect => 2,
+            Self::TooHigh => 3,
+            Self::TooLow => 4,
+            Self::AlreadyCompleted => 5,
+            Self::PleaseWait => 6,
+            Self::Unknown => 7,
+        }
+    }
+
+    #[must_use]
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Incorrect => "incorrect",
+            Self::AlreadyCompleted => "already_completed",
+            Self::PleaseWait => "please_wait",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::str::FromStr for Verdict {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too_high" => Ok(Self::TooHigh),
+            "too_low" => Ok(Self::TooLow),
+            "incorrect" => Ok(Self::Incorrect),
+            "already_completed" => Ok(Self::AlreadyCompleted),
+            "please_wait" => Ok(Self::PleaseWait),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "wrong, too high",
+            Self::TooLow => "wrong, too low",
+            Self::Incorrect => "wrong",
+            Self::AlreadyCompleted => "already completed",
+            Self::PleaseWait => "submitted too recently",
+            Self::Unknown => "unknown response",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// Response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// The response message, converted to markdown.
+    pub message: String,
+}
+
+impl Submission {
+    /// Returns how long to wait before submitting again, parsed from e.g. "You have 1m 37s left to wait".
+    #[must_use]
+    pub fn wait_time(&self) -> Option<Duration> {
+        let start = self.message.find("You have ")? + "You have ".len();
+        let end = start + self.message[start..].find(" left to wait")?;
+
+        self.message[start..end]
+            .split_whitespace()
+            .try_fold(Duration::ZERO, |total, component| {
+                let unit_start = component.find(|c: char| !c.is_ascii_digit())?;
+                let value: u64 = component[..unit_start].parse().ok()?;
+                let seconds = match &component[unit_start..] {
+                    "s" => value,
+                    "m" => value * 60,
+                    "h" => value * 60 * 60,
+                    _ => return None,
+                };
+                Some(total + Duration::from_secs(seconds))
+            })
+            .filter(|total| !total.is_zero())
+    }
+}
+
+/// HTTP client for a single event.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(session: String, year: Year) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.into(),
+            session,
+            year,
+        }
+    }
+
+    /// Creates a client for a year from the configured session cookie and `AOC_BASE_URL`.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = session::load().ok_or(AocClientError::SessionNotFound)?;
+        let client = Self::new(session.cookie, year);
+
+        Ok(match env::var("AOC_BASE_URL") {
+            Ok(base_url) if !base_url.is_empty() => client.with_base_url(&base_url),
+            _ => client,
+        })
+    }
+
+    /// Points the client at another server, e.g. a mock server in tests.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').into();
+        self
+    }
+
+    #[must_use]
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    /// Checks that the session cookie is accepted by the server.
+    pub fn check_session(&self) -> Result<(), AocClientError> {
+        // a logged out user is redirected to the login page, so redirects must not be followed.
+        let agent = ureq::AgentBuilder::new().redirects(0).build();
+        let request = agent.get(&format!("{}/settings", self.base_url));
+        let status = self.send(request, None)?;
+
+        if (300..400).contains(&status.0) || is_logged_out(&status.1) {
+            return Err(AocClientError::SessionExpired);
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn get_input(&self, day: Day) -> Result<String, AocClientError> {
+        let request = ureq::get(&self.day_url(day, "/input"));
+        self.call(request, None)
+    }
+
+    /// Fetches the puzzle description for a day, converted to markdown.
+    /// Part two is included once part one has been solved.
+    pub fn get_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let request = ureq::get(&self.day_url(day, ""));
+        let page = self.call(request, None)?;
+        Ok(html::articles_to_markdown(&page))
+    }
+
+    /// Submits an answer for a part and classifies the response.
+    pub fn submit(&self, day: Day, part: Part, answer: &str) -> Result<Submission, AocClientError> {
+        let request = ureq::post(&self.day_url(day, "/answer"));
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let page = self.call(request, Some(&form))?;
+        let message = html::articles_to_markdown(&page);
+
+        Ok(Submission {
+            verdict: Verdict::from_message(&message),
+            message,
+        })
+    }
+
+    fn day_url(&self, day: Day, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{path}",
+            self.base_url,
+            self.year,
+            day.into_inner()
+        )
+    }
+
+    /// Sends a request and returns the body of a successful response.
+    fn call(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, String)]>,
+    ) -> Result<String, AocClientError> {
+        self.send(request, form).map(|(_, body)| body)
+    }
+
+    /// Sends a request and returns the status code and body of a response that is not an error.
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, String)]>,
+    ) -> Result<(u16, String), AocClientError> {
+        let request = request
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT);
+
+        let response = match form {
+            Some(form) => {
+                let form: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
+                request.send_form(&form)
+            }
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => Ok((response.status(), response.into_string()?)),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                if is_logged_out(&body) {
+                    Err(AocClientError::SessionExpired)
+                } else {
+                    Err(AocClientError::BadStatus(status, body))
+                }
+            }
+            Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(e.to_string())),
+        }
+    }
+}
+
+/// Whether a response asks the user to log in, e.g. "Puzzle inputs differ by user. Please log in to get your puzzle input."
+fn is_logged_out(body: &str) -> bool {
+    body.contains("Please log in") || body.contains("/auth/login")
+}
+
+/// Downloads the input and puzzle description of a day into `data/YYYY/`.
+/// Before the puzzle unlocks, this fails unless `wait` is set, in which case it waits for the unlock.
+pub fn download(id: PuzzleId, wait: bool) -> Result<(), AocClientError> {
+    let remaining = time_until_unlock(id);
+    if !remaining.is_zero() && !wait {
+        return Err(AocClientError::NotUnlocked(remaining));
+    }
+
+    // the session is checked before waiting, so a missing cookie does not show up only after the countdown.
+    let client = AocClient::from_env(id.year)?;
+    let input_path = get_input_path(id);
+    let puzzle_path = puzzle::get_path(id);
+
+    if !remaining.is_zero() {
+        countdown(
+            &format!("Day {} of {} unlocks in", id.day, id.year),
+            remaining,
+        );
+    }
+
+    println!(
+        "Fetching input and puzzle for day {}, {}...",
+        id.day, id.year
+    );
+    let input = client.get_input(id.day)?;
+    let puzzle = client.get_puzzle(id.day)?;
+
+    write_file(&input_path, input)?;
+    write_file(&puzzle_path, puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Fetches the puzzle description of a day and prints it.
+/// The local copy is updated as well, so it includes part two once part one is solved.
+pub fn read(id: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(id.year)?;
+    println!("Fetching puzzle for day {}, {}...\n", id.day, id.year);
+    let puzzle = client.get_puzzle(id.day)?;
+    println!("{puzzle}");
+    write_file(puzzle::get_path(id), puzzle)?;
+    Ok(())
+}
+
+/// Fetches the puzzle description of a day again and overwrites `data/YYYY/puzzles/DD.md` with it.
+/// Part two is only part of the description after part one was solved.
+pub fn update_puzzle(id: PuzzleId) -> Result<String, AocClientError> {
+    let puzzle = AocClient::from_env(id.year)?.get_puzzle(id.day)?;
+    write_file(puzzle::get_path(id), &puzzle)?;
+    Ok(puzzle)
+}
+
+/// Submits an answer for a part and classifies the response.
+pub fn submit(id: PuzzleId, part: Part, result: &str) -> Result<Submission, AocClientError> {
+    AocClient::from_env(id.year)?.submit(id.day, part, result)
+}
+
+fn get_input_path(id: PuzzleId) -> String {
+    get_data_path("inputs", id, ".txt")
+}
+
+/// Reads the default year from `AOC_YEAR`, falling back to the most recent event.
+pub fn default_year() -> Result<Year, AocClientError> {
+    Ok(Year::from_env()
+        .map_err(AocClientError::InvalidYear)?
+        .unwrap_or_else(latest_event_year))
+}
+
+/// Returns the year of the most recent event, i.e. the last year whose first puzzle has unlocked.
+fn latest_event_year() -> Year {
+    let (year, _) = year_month_from_days(now_secs() / 86_400);
+    let year = Year::new(year).unwrap_or(year!(2015));
+    let first_puzzle = PuzzleId::new(year, day!(1)).expect("every event has a first day");
+
+    if now_secs() >= unlock_secs(first_puzzle) {
+        year
+    } else {
+        Year::new(year.into_inner() - 1).unwrap_or(year)
+    }
+}
+
+/// Returns how long it takes until a puzzle unlocks, or zero if it already has.
+#[must_use]
+pub fn time_until_unlock(id: PuzzleId) -> Duration {
+    Duration::from_secs(unlock_secs(id).saturating_sub(now_secs()))
+}
+
+/// Returns the unix timestamp at which a puzzle unlocks.
+/// Puzzles unlock at midnight US Eastern time (UTC-5), which is 05:00 UTC in December.
+fn unlock_secs(id: PuzzleId) -> u64 {
+    days_from_civil(id.year.into_inner(), 12, id.day.into_inner()) * 86_400 + 5 * 3600
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Converts a calendar date to days since the unix epoch, dates before the epoch are not supported.
+/// see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: u16, month: u8, day: u8) -> u64 {
+    let (month, day) = (u64::from(month), u64::from(day));
+    let year = u64::from(year) - u64::from(month <= 2);
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since the unix epoch to a calendar year and month.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+#[allow(clippy::cast_possible_truncation)]
+fn year_month_from_days(days: u64) -> (u16, u8) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year as u16, month as u8)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        days_from_civil, unlock_secs, year_month_from_days, AocClient, AocClientError, Submission,
+        Verdict,
+    };
+    use crate::{day, year, Part, PuzzleId};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    /// Serves a single request with a canned response and returns the raw request.
+    fn mock_server(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body_buf = vec![0; content_length];
+            reader.read_exact(&mut body_buf).unwrap();
+            request.push_str(&String::from_utf8(body_buf).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn client(base_url: &str) -> AocClient {
+        AocClient::new("abc123".into(), year!(2023)).with_base_url(base_url)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (base_url, server) = mock_server(200, "1abc2\n");
+        let input = client(&base_url).get_input(day!(1)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1abc2\n");
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains("User-Agent: github.com/fspoettel/advent-of-code-rust\r\n"));
+    }
+
+    #[test]
+    fn fetches_puzzle() {
+        let (base_url, server) = mock_server(
+            200,
+            "<main><article><h2>--- Day 5 ---</h2><p>Hello.</p></article></main>",
+        );
+        let puzzle = client(&base_url).get_puzzle(day!(5)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(puzzle, "## --- Day 5 ---\n\nHello.");
+        assert!(request.starts_with("GET /2023/day/5 HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, server) = mock_server(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let submission = client(&base_url)
+            .submit(day!(12), Part::Two, "4 2")
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(submission.verdict, Verdict::Correct);
+        assert_eq!(submission.message, "That's the right answer!");
+        assert!(request.starts_with("POST /2023/day/12/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=4+2"));
+    }
+
+    #[test]
+    fn reports_expired_session() {
+        let (base_url, server) = mock_server(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let result = client(&base_url).get_input(day!(1));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::SessionExpired)));
+    }
+
+    #[test]
+    fn checks_session() {
+        let (base_url, server) = mock_server(200, "<main>Settings</main>");
+        let result = client(&base_url).check_session();
+        let request = server.join().unwrap();
+        assert!(result.is_ok());
+        assert!(request.starts_with("GET /settings HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+
+        let (base_url, server) = mock_server(302, "");
+        let result = client(&base_url).check_session();
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::SessionExpired)));
+
+        let (base_url, server) = mock_server(200, "<a href=\"/2023/auth/login\">[Log In]</a>");
+        let result = client(&base_url).check_session();
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::SessionExpired)));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = mock_server(400, "Puzzle inputs differ by user.");
+        let result = client(&base_url).get_input(day!(1));
+        server.join().unwrap();
+
+        assert!(matches!(
+            result,
+            Err(AocClientError::BadStatus(400, body)) if body == "Puzzle inputs differ by user."
+        ));
+    }
+
+    #[test]
+    fn classifies_verdicts() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer to restoring snow operations.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low. Please wait one minute before trying again.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data.", Verdict::Incorrect),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.", Verdict::PleaseWait),
+            ("You don't seem to be solving the right level. Did you already complete it? [[Return to Day 1]](/2023/day/1)", Verdict::AlreadyCompleted),
+            ("", Verdict::Unknown),
+        ];
+
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::from_message(message), verdict, "{message}");
+        }
+    }
+
+    #[test]
+    fn parses_wait_time() {
+        let wait_time = |message: &str| {
+            Submission {
+                verdict: Verdict::from_message(message),
+                message: message.into(),
+            }
+            .wait_time()
+        };
+
+        assert_eq!(
+            wait_time("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 37s left to wait. [[Return to Day 1]](/2023/day/1)"),
+            Some(Duration::from_secs(37))
+        );
+        assert_eq!(
+            wait_time("You gave an answer too recently. You have 1m 5s left to wait."),
+            Some(Duration::from_secs(65))
+        );
+        assert_eq!(
+            wait_time("You gave an answer too recently. You have 4m left to wait."),
+            Some(Duration::from_secs(240))
+        );
+        assert_eq!(wait_time("You have a while left to wait."), None);
+        assert_eq!(wait_time("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            unlock_secs(PuzzleId::new(year!(2023), day!(1)).unwrap()),
+            1_701_406_800
+        );
+        // 2024-12-25T05:00:00Z
+        assert_eq!(
+            unlock_secs(PuzzleId::new(year!(2024), day!(25)).unwrap()),
+            1_735_102_800
+        );
+    }
+
+    #[test]
+    fn converts_days_to_year_and_month() {
+        assert_eq!(year_month_from_days(0), (1970, 1));
+        // 2023-12-01
+        assert_eq!(year_month_from_days(19_692), (2023, 12));
+        // 2024-02-29
+        assert_eq!(year_month_from_days(19_782), (2024, 2));
+    }
+}
@@ -1,29 +1,156 @@
-use std::io;
+use std::{fs, io, process, time::Duration};
 
 use crate::template::{
+    alloc::format_bytes,
+    answers::Answers,
     readme_benchmarks::{self, Timings},
+    report::Phase,
+    runner::BenchConfig,
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{AllDays, DaySet, Part, PuzzleId, Year};
+
+/// The days that `all` runs.
+#[derive(Debug, Clone)]
+pub struct DaySelection {
+    /// The years to run the days of.
+    pub years: Vec<Year>,
+    /// Only run these days (`--days`), all days if [`None`].
+    pub days: Option<DaySet>,
+    /// Do not run these days (`--skip`).
+    pub skip: Option<DaySet>,
+    /// Only run days that miss a confirmed answer for a part (`--only-unsolved-answers`).
+    pub only_unsolved_answers: bool,
+}
+
+impl DaySelection {
+    /// Returns the selected puzzles, in order.
+    #[must_use]
+    pub fn puzzles(&self) -> Vec<PuzzleId> {
+        self.years
+            .iter()
+            .flat_map(|&year| AllDays::in_year(year.days()).map(move |day| PuzzleId { year, day }))
+            .filter(|puzzle| self.includes(*puzzle))
+            .collect()
+    }
+
+    /// Whether all days of the years are selected.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.days.is_none() && self.skip.is_none() && !self.only_unsolved_answers
+    }
+
+    fn includes(&self, puzzle: PuzzleId) -> bool {
+        self.days
+            .as_ref()
+            .is_none_or(|days| days.contains(puzzle.day))
+            && !self
+                .skip
+                .as_ref()
+                .is_some_and(|skip| skip.contains(puzzle.day))
+            && (!self.only_unsolved_answers || has_unsolved_answers(puzzle))
+    }
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
+/// Whether a part with a puzzle has no confirmed answer. Days with an invalid answers file count as unsolved.
+fn has_unsolved_answers(puzzle: PuzzleId) -> bool {
+    let Ok(answers) = Answers::load(puzzle) else {
+        return true;
+    };
+
+    Part::ALL
+        .into_iter()
+        .filter(|part| part.has_puzzle(puzzle))
+        .any(|part| answers.get(part).is_none())
+}
+
+/// Returns the parts of a puzzle that run, all parts with a puzzle unless `--part` selects one.
+fn selected_parts(puzzle: PuzzleId, part: Option<Part>) -> Vec<Part> {
+    Part::ALL
+        .into_iter()
+        .filter(|p| part.is_none_or(|part| part == *p) && p.has_puzzle(puzzle))
+        .collect()
+}
+
+pub fn handle(
+    selection: &DaySelection,
+    is_release: bool,
+    is_timed: bool,
+    bench: &BenchConfig,
+    timeout: Option<Duration>,
+    day_timeout: Option<Duration>,
+    part: Option<Part>,
+) {
     let mut timings: Vec<Timings> = vec![];
+    let mut failures = 0;
 
-    all_days().for_each(|day| {
-        if day > 1 {
+    let puzzles = selection.puzzles();
+
+    if puzzles.is_empty() {
+        println!("No days selected.");
+        return;
+    }
+
+    puzzles.into_iter().enumerate().for_each(|(i, puzzle)| {
+        if i > 0 {
             println!();
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        // days are only labelled with their year if several years are run.
+        if selection.years.len() > 1 {
+            println!("{ANSI_BOLD}Day {}, {}{ANSI_RESET}", puzzle.day, puzzle.year);
+        } else {
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        }
         println!("------");
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let reports = match child_commands::run_solution(
+            puzzle,
+            is_timed,
+            is_release,
+            bench,
+            timeout,
+            day_timeout,
+            part,
+        ) {
+            Ok(reports) => reports,
+            Err(Error::Build) => {
+                // a solution that does not compile fails all of its parts.
+                println!("✖ {ANSI_BOLD}build failed{ANSI_RESET}");
+                failures += selected_parts(puzzle, part).len();
+                return;
+            }
+            Err(e) => panic!("failed to run solution: {e:?}"),
+        };
+
+        failures += reports.iter().filter(|r| r.is_failure()).count();
+
+        // a part with a confirmed answer regressed if it did not report at all, e.g. after its module was removed.
+        let answers = Answers::load(puzzle).unwrap_or_default();
+        for part in selected_parts(puzzle, part) {
+            let Some(expected) = answers.get(part) else {
+                continue;
+            };
+            if !reports.iter().any(|r| r.phase == Phase::Part(part)) {
+                println!("Part {part}: ✘ {ANSI_BOLD}no result{ANSI_RESET}, expected {expected}");
+                failures += 1;
+            }
+        }
+
+        if let Some(resources) = child_commands::sum_resources(&reports) {
+            println!(
+                "Resources: {ANSI_ITALIC}peak RSS {} · CPU {:.1?} (user {:.1?} · sys {:.1?}){ANSI_RESET}",
+                format_bytes(resources.peak_rss_bytes),
+                resources.cpu_time(),
+                resources.user_time,
+                resources.system_time
+            );
+        }
 
-        if output.is_empty() {
+        if reports.is_empty() {
             println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        } else if is_timed {
+            timings.push(child_commands::collect_timings(&reports, puzzle));
         }
     });
 
@@ -32,19 +159,29 @@ pub fn handle(is_release: bool, is_timed: bool) {
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
-        if is_release {
+        // timings of a single part or some days would leave the benchmark table incomplete.
+        if is_release && part.is_none() && selection.is_complete() {
             match readme_benchmarks::update(timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+        } else if is_release {
+            println!("Skipped updating README with benchmarks, not all parts were run.");
         }
     }
+
+    if failures > 0 {
+        eprintln!("\n{failures} part(s) failed.");
+        process::exit(1);
+    }
 }
 
 #[derive(Debug)]
 pub enum Error {
+    /// The solution binary failed to compile.
+    Build,
     BrokenPipe,
     Parser(String),
     IO(io::Error),
@@ -56,47 +193,87 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Returns all years that have at least one solution in `src/bin`, in ascending order.
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn solution_years() -> Vec<Year> {
+    let mut years: Vec<Year> = fs::read_dir("src/bin")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let (year, _) = name.to_str()?.strip_suffix(".rs")?.split_once('_')?;
+            year.parse().ok()
+        })
+        .collect();
+
+    years.sort_unstable();
+    years.dedup();
+    years
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as consuming their reports.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+    use super::Error;
+    use crate::template::alloc::format_bytes;
+    use crate::template::readme_benchmarks::get_path_for_bin;
+    use crate::template::report::{PartReport, Phase, Status};
+    use crate::template::resources::ResourceUsage;
+    use crate::template::runner::{print_report, BenchConfig, TIMEOUT_EXIT_CODE};
+    use crate::{Part, PuzzleId};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
+        process::{Child, Command, ExitStatus, Stdio},
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        bench: &BenchConfig,
+        timeout: Option<Duration>,
+        day_timeout: Option<Duration>,
+        part: Option<Part>,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // the binary is invoked directly instead of via `cargo run`, so a timeout kills the solution itself.
+        let Some(executable) = build_solution(puzzle, is_release)? else {
+            return Err(Error::Build);
+        };
 
-        if is_release {
-            args.push("--release");
+        // request machine-readable output from child invocations.
+        let mut args: Vec<String> = vec!["--format".into(), "json".into()];
+
+        if let Some(timeout) = timeout {
+            args.push("--timeout".into());
+            args.push(format!("{}ns", timeout.as_nanos()));
+        }
+
+        if let Some(part) = part {
+            args.push("--part".into());
+            args.push(part.to_string());
         }
 
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            // mirror `--time` flag and bench configuration to child invocations.
+            args.push("--time".into());
+            args.append(&mut bench.to_args());
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while collecting reports.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -105,85 +282,186 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
+        let stderr_thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let stdout_thread = thread::spawn(move || {
+            let mut reports = vec![];
+
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                // lines that are not reports (e.g. debug output of a solution) are forwarded as-is.
+                match PartReport::from_json(&line) {
+                    Some(report) => {
+                        print_report(&report);
+                        reports.push(report);
+                    }
+                    None => println!("{line}"),
+                }
+            }
+
+            reports
+        });
+
+        let status = match day_timeout {
+            Some(day_timeout) => wait_with_timeout(&mut cmd, day_timeout)?,
+            None => Some(cmd.wait()?),
+        };
+
+        let mut reports = stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+
+        // parts that did not report before the child stopped are marked as timed out if the day timeout
+        // killed it or the watchdog of a part exited it, and as crashed if it aborted or exited otherwise.
+        let missing_report = |phase| match status {
+            None => day_timeout.map(|t| PartReport::timed_out(puzzle.day, phase, t)),
+            Some(status) if status.code() == Some(TIMEOUT_EXIT_CODE) => {
+                timeout.map(|t| PartReport::timed_out(puzzle.day, phase, t))
+            }
+            Some(status) if !status.success() => Some(PartReport::crashed(
+                puzzle.day,
+                phase,
+                format!("solution exited with {status}"),
+                Duration::ZERO,
+            )),
+            Some(_) => None,
+        };
+
+        for part in super::selected_parts(puzzle, part) {
+            if reports.iter().any(|r| r.phase == Phase::Part(part)) {
+                continue;
+            }
+
+            if let Some(report) = missing_report(Phase::Part(part)) {
+                print_report(&report);
+                reports.push(report);
+            }
+        }
+
+        Ok(reports)
+    }
+
+    /// Build the solution bin for a given puzzle and return the path to its executable.
+    /// Returns [`None`] if the build failed, compiler output is forwarded to stderr.
+    fn build_solution(puzzle: PuzzleId, is_release: bool) -> Result<Option<PathBuf>, Error> {
+        let bin_name = puzzle.bin_name();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            "--message-format",
+            "json-render-diagnostics",
+        ];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        // mirror allocation accounting to the solution binary.
+        if cfg!(feature = "alloc_stats") {
+            args.push("--features");
+            args.push("alloc_stats");
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-        Ok(output)
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| parse_executable(line, &bin_name)))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Extract the executable path from a cargo `compiler-artifact` message for the bin `name`.
+    fn parse_executable(line: &str, name: &str) -> Option<PathBuf> {
+        let value: JsonValue = line.parse().ok()?;
+        let message: &HashMap<String, JsonValue> = value.get()?;
+        let reason: &String = message.get("reason")?.get()?;
+        let target: &HashMap<String, JsonValue> = message.get("target")?.get()?;
+        let target_name: &String = target.get("name")?.get()?;
+        let executable: &String = message.get("executable")?.get()?;
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        (reason == "compiler-artifact" && target_name == name).then(|| PathBuf::from(executable))
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Wait for the child to exit, killing it once `timeout` has passed.
+    /// Returns the exit status, or [`None`] if the child was killed.
+    fn wait_with_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        let deadline = Instant::now() + timeout;
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
 
-                timings.total_nanos += nanos;
-            });
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
 
-        timings
+            thread::sleep(Duration::from_millis(10));
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Combine the resources of all phases of a day: the highest peak memory and the sum of CPU times.
+    /// Returns [`None`] if no phase reported its resources.
+    pub fn sum_resources(reports: &[PartReport]) -> Option<ResourceUsage> {
+        reports
+            .iter()
+            .filter_map(|report| report.resources)
+            .reduce(|acc, resources| ResourceUsage {
+                peak_rss_bytes: acc.peak_rss_bytes.max(resources.peak_rss_bytes),
+                user_time: acc.user_time + resources.user_time,
+                system_time: acc.system_time + resources.system_time,
+            })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    pub fn collect_timings(reports: &[PartReport], puzzle: PuzzleId) -> super::Timings {
+        let resources = sum_resources(reports);
+
+        let mut timings = super::Timings {
+            puzzle,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            peak_rss: resources.map(|r| format_bytes(r.peak_rss_bytes)),
+            cpu_time: resources.map(|r| format!("{:.1?}", r.cpu_time())),
+            total_nanos: 0_f64,
+        };
+
+        for report in reports {
+            let timing_str = match report.status {
+                Status::TimedOut => "timed out".into(),
+                Status::Crashed => "crashed".into(),
+                _ => format!("{:.1?}", report.stats.median),
+            };
+
+            match report.phase {
+                Phase::Parse => timings.parse = Some(timing_str),
+                Phase::Part(Part::One) => timings.part_1 = Some(timing_str),
+                Phase::Part(Part::Two) => timings.part_2 = Some(timing_str),
+            }
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            // a part that failed did not finish, its time would distort the total.
+            if !report.status.is_failure() {
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    timings.total_nanos += report.stats.median.as_nanos() as f64;
+                }
+            }
+        }
 
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -202,53 +480,196 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{collect_timings, parse_executable};
+
+        use crate::template::answers::AnswerCheck;
+        use crate::template::report::{PartReport, Phase, Status};
+        use crate::template::resources::ResourceUsage;
+        use crate::template::runner::Stats;
+        use crate::{day, puzzle, Part};
+        use std::time::Duration;
+
+        fn get_mock_report(phase: Phase, median: Duration) -> PartReport {
+            PartReport {
+                day: day!(1),
+                phase,
+                status: Status::Solved,
+                answer: Some("0".into()),
+                error: None,
+                allocations: None,
+                resources: None,
+                check: AnswerCheck::Unknown,
+                expected: None,
+                stats: Stats {
+                    median,
+                    samples: 100,
+                    ..Stats::single(median)
+                },
+            }
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
+            let res = collect_timings(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    get_mock_report(Phase::Part(Part::One), Duration::from_nanos(74)),
+                    get_mock_report(Phase::Part(Part::Two), Duration::from_micros(74_130)),
                 ],
-                day!(1),
+                puzzle!(2023, 1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.parse.is_none(), true);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
+        fn test_with_parse() {
+            let res = collect_timings(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    get_mock_report(Phase::Parse, Duration::from_micros(10)),
+                    get_mock_report(Phase::Part(Part::One), Duration::from_micros(20)),
+                    get_mock_report(Phase::Part(Part::Two), Duration::from_micros(30)),
                 ],
-                day!(1),
+                puzzle!(2023, 1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 60000_f64);
+            assert_eq!(res.parse.unwrap(), "10.0µs");
+            assert_eq!(res.part_1.unwrap(), "20.0µs");
+            assert_eq!(res.part_2.unwrap(), "30.0µs");
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
+            let res = collect_timings(
+                &[get_mock_report(
+                    Phase::Part(Part::One),
+                    Duration::from_secs(2),
+                )],
+                puzzle!(2023, 1),
+            );
+            assert_approx_eq!(res.total_nanos, 2000000000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn test_timed_out() {
+            let res = collect_timings(
+                &[
+                    get_mock_report(Phase::Part(Part::One), Duration::from_micros(20)),
+                    PartReport::timed_out(day!(1), Phase::Part(Part::Two), Duration::from_secs(10)),
+                ],
+                puzzle!(2023, 1),
+            );
+            assert_approx_eq!(res.total_nanos, 20000_f64);
+            assert_eq!(res.part_1.unwrap(), "20.0µs");
+            assert_eq!(res.part_2.unwrap(), "timed out");
+        }
+
+        #[test]
+        fn test_crashed() {
+            let res = collect_timings(
+                &[
+                    get_mock_report(Phase::Part(Part::One), Duration::from_micros(20)),
+                    PartReport::crashed(
+                        day!(1),
+                        Phase::Part(Part::Two),
+                        "panicked: boom".into(),
+                        Duration::from_millis(3),
+                    ),
+                ],
+                puzzle!(2023, 1),
+            );
+            assert_approx_eq!(res.total_nanos, 20000_f64);
+            assert_eq!(res.part_2.unwrap(), "crashed");
+        }
+
+        #[test]
+        fn test_resources() {
+            let with_resources = |peak_rss_bytes, user_ms| PartReport {
+                resources: Some(ResourceUsage {
+                    peak_rss_bytes,
+                    user_time: Duration::from_millis(user_ms),
+                    system_time: Duration::from_millis(1),
+                }),
+                ..get_mock_report(Phase::Part(Part::One), Duration::from_millis(user_ms))
+            };
+
+            let res = collect_timings(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    with_resources(2 * 1024 * 1024, 10),
+                    with_resources(1024, 20),
+                    get_mock_report(Phase::Part(Part::Two), Duration::from_millis(5)),
                 ],
-                day!(1),
+                puzzle!(2023, 1),
+            );
+            assert_eq!(res.peak_rss.unwrap(), "2.0 MiB");
+            assert_eq!(res.cpu_time.unwrap(), "32.0ms");
+
+            let res = collect_timings(
+                &[get_mock_report(
+                    Phase::Part(Part::One),
+                    Duration::from_millis(5),
+                )],
+                puzzle!(2023, 1),
+            );
+            assert_eq!(res.peak_rss.is_none(), true);
+            assert_eq!(res.cpu_time.is_none(), true);
+        }
+
+        #[test]
+        fn test_parse_executable() {
+            let line = r#"{"reason":"compiler-artifact","target":{"name":"2023_01","kind":["bin"]},"executable":"/tmp/target/release/2023_01","fresh":true}"#;
+            assert_eq!(
+                parse_executable(line, "2023_01"),
+                Some("/tmp/target/release/2023_01".into())
             );
+            assert_eq!(parse_executable(line, "2023_02"), None);
+            assert_eq!(parse_executable(line, "2024_01"), None);
+
+            let lib = r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code","kind":["lib"]},"executable":null}"#;
+            assert_eq!(parse_executable(lib, "advent_of_code"), None);
+            assert_eq!(parse_executable("not json", "2023_01"), None);
+        }
+
+        #[test]
+        fn test_no_reports() {
+            let res = collect_timings(&[], puzzle!(2023, 1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySelection;
+    use crate::year;
+
+    #[test]
+    fn selects_days() {
+        let selection = DaySelection {
+            years: vec![year!(2023), year!(2025)],
+            days: Some("1-3,11-".parse().unwrap()),
+            skip: Some("2,25".parse().unwrap()),
+            only_unsolved_answers: false,
+        };
+        let puzzles: Vec<String> = selection
+            .puzzles()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            puzzles,
+            [
+                "2023/01", "2023/03", "2023/11", "2023/12", "2023/13", "2023/14", "2023/15",
+                "2023/16", "2023/17", "2023/18", "2023/19", "2023/20", "2023/21", "2023/22",
+                "2023/23", "2023/24", "2025/01", "2025/03", "2025/11", "2025/12",
+            ]
+        );
+        assert!(!selection.is_complete());
+    }
+}
@@ -0,0 +1,223 @@
+use std::{fs, process};
+
+use crate::template::puzzle::{self, PuzzlePart};
+use crate::template::{get_data_path, get_module_path, puzzle_args, write_file};
+use crate::{Part, PuzzleId};
+
+/// Proposes the examples and expected answers of a downloaded puzzle, or writes them with `write`.
+/// Existing example files are only overwritten with `force`.
+pub fn handle(puzzle: PuzzleId, write: bool, force: bool) {
+    let parts = match puzzle::load(puzzle) {
+        Ok(parts) => parts,
+        Err(e) => {
+            eprintln!(
+                "Failed to read {}: {e}. Run `cargo download {}` first.",
+                puzzle::get_path(puzzle),
+                puzzle_args(puzzle)
+            );
+            process::exit(1);
+        }
+    };
+
+    if parts.iter().all(|part| part.example().is_none()) {
+        eprintln!("No examples found in {}.", puzzle::get_path(puzzle));
+        process::exit(1);
+    }
+
+    for (part, puzzle_part) in Part::ALL.into_iter().zip(&parts) {
+        if write {
+            write_part(puzzle, part, &parts, force);
+        } else {
+            print_part(part, puzzle_part);
+        }
+    }
+
+    if !write {
+        println!("---");
+        println!(
+            "🎄 Type `cargo example {} --write` to write the examples and fill in the tests.",
+            puzzle_args(puzzle)
+        );
+    }
+}
+
+/// Prints the example and expected answer found in a part.
+pub fn print_part(part: Part, puzzle_part: &PuzzlePart) {
+    println!("Part {part}:");
+
+    match puzzle_part.example() {
+        Some(example) => println!("```\n{example}```"),
+        None => println!("No example, the example of part 1 applies."),
+    }
+
+    if puzzle_part.examples.len() > 1 {
+        println!(
+            "({} more code block(s) were skipped.)",
+            puzzle_part.examples.len() - 1
+        );
+    }
+
+    match puzzle_part.answer() {
+        Some(answer) => println!("Expected answer: {answer}"),
+        None => println!("Expected answer: not found"),
+    }
+
+    if let Some((_, others)) = puzzle_part.emphasized.split_last() {
+        if !others.is_empty() {
+            println!("Other emphasized values: {}", others.join(", "));
+        }
+    }
+
+    println!();
+}
+
+/// Writes the example of a part and fills in the expected answer of its test.
+/// Part two only gets a separate example file if its example differs from part one.
+pub fn write_part(puzzle: PuzzleId, part: Part, parts: &[PuzzlePart], force: bool) {
+    let Some(puzzle_part) = parts.get(usize::from(part.into_inner()) - 1) else {
+        return;
+    };
+
+    let example = puzzle_part.example();
+    let is_separate = part == Part::Two && example.is_some() && example != parts[0].example();
+
+    if let Some(example) = example.filter(|_| part == Part::One || is_separate) {
+        let path = get_example_path(puzzle, part);
+
+        if !force && fs::read_to_string(&path).is_ok_and(|s| !s.trim().is_empty()) {
+            println!("Skipped \"{path}\", it is not empty. Pass `--force` to overwrite it.");
+        } else {
+            match write_file(&path, example) {
+                Ok(()) => println!("Wrote example of part {part} to \"{path}\""),
+                Err(e) => {
+                    eprintln!("Failed to write example file: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    match puzzle_part.answer() {
+        Some(answer) => fill_test(puzzle, part, answer, is_separate),
+        None => println!("No expected answer found for part {part}."),
+    }
+}
+
+/// Replaces the `None` assertion of a scaffolded test with the expected answer.
+fn fill_test(puzzle: PuzzleId, part: Part, answer: &str, is_separate: bool) {
+    let path = get_module_path(puzzle);
+
+    let Ok(module) = fs::read_to_string(&path) else {
+        println!("Skipped filling in test for part {part}, \"{path}\" does not exist.");
+        return;
+    };
+
+    if answer.parse::<i64>().is_err() {
+        println!("Expected answer of part {part} is `{answer}`, fill in the test by hand as it is not a number.");
+        return;
+    }
+
+    match fill_test_source(&module, part, answer, is_separate) {
+        Some(module) => match fs::write(&path, module) {
+            Ok(()) => println!("Filled in expected answer `{answer}` of part {part} in \"{path}\""),
+            Err(e) => {
+                eprintln!("Failed to write module file: {e}");
+                process::exit(1);
+            }
+        },
+        None => println!("Skipped filling in test for part {part}, it was already changed."),
+    }
+}
+
+/// Fills in the expected answer in the test of a part, returns [`None`] if the test does not assert `None` anymore.
+fn fill_test_source(module: &str, part: Part, answer: &str, is_separate: bool) -> Option<String> {
+    let (name, variant) = match part {
+        Part::One => ("part_one", "One"),
+        Part::Two => ("part_two", "Two"),
+    };
+    let start = module.find(&format!("fn test_{name}()"))?;
+    // the test ends where the next function starts.
+    let end = module[start + 1..]
+        .find("fn ")
+        .map_or(module.len(), |i| start + 1 + i);
+
+    let test = &module[start..end];
+    let assertion = "assert_eq!(result, None);";
+    test.find(assertion)?;
+
+    let mut filled = test.replace(assertion, &format!("assert_eq!(result, Some({answer}));"));
+
+    if is_separate {
+        filled = filled.replace(
+            "read_file(\"examples\", PUZZLE)",
+            &format!("read_file_part(\"examples\", PUZZLE, advent_of_code::Part::{variant})"),
+        );
+    }
+
+    Some(format!("{}{filled}{}", &module[..start], &module[end..]))
+}
+
+fn get_example_path(puzzle: PuzzleId, part: Part) -> String {
+    match part {
+        Part::One => get_data_path("examples", puzzle, ".txt"),
+        Part::Two => get_data_path("examples", puzzle, &format!("-{part}.txt")),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::fill_test_source;
+    use crate::Part;
+
+    const MODULE: &str = r#"pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn fills_in_part_one() {
+        let filled = fill_test_source(MODULE, Part::One, "142", false).unwrap();
+        assert_eq!(filled.matches("assert_eq!(result, Some(142));").count(), 1);
+        assert_eq!(filled.matches("assert_eq!(result, None);").count(), 1);
+        assert!(filled.find("Some(142)") < filled.find("fn test_part_two"));
+    }
+
+    #[test]
+    fn fills_in_separate_part_two() {
+        let filled = fill_test_source(MODULE, Part::Two, "281", true).unwrap();
+        assert!(filled.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, advent_of_code::Part::Two));\n        assert_eq!(result, Some(281));"
+        ));
+        assert!(filled
+            .contains("part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));"));
+    }
+
+    #[test]
+    fn skips_changed_tests() {
+        let filled = fill_test_source(MODULE, Part::One, "142", false).unwrap();
+        assert_eq!(fill_test_source(&filled, Part::One, "142", false), None);
+        assert_eq!(
+            fill_test_source("fn main() {}", Part::One, "142", false),
+            None
+        );
+    }
+}
@@ -1,5 +1,7 @@
 pub mod all;
 pub mod download;
+pub mod example;
 pub mod read;
 pub mod scaffold;
+pub mod session;
 pub mod solve;
@@ -0,0 +1,77 @@
+use std::io::{self, IsTerminal};
+use std::process;
+
+use crate::template::{
+    aoc_client::{self, AocClient},
+    session,
+};
+
+pub enum SessionCommand {
+    /// Store a cookie in the home file, or in the project file with `--project`.
+    /// The cookie is read from stdin if it is not passed, which keeps it out of the shell history.
+    Set {
+        cookie: Option<String>,
+        project: bool,
+    },
+    /// Print the cookie in use and where it was read from.
+    Show,
+    /// Validate the cookie in use against the server.
+    Check,
+}
+
+pub fn handle(command: SessionCommand) {
+    match command {
+        SessionCommand::Set { cookie, project } => match cookie
+            .map_or_else(read_cookie, Ok)
+            .and_then(|cookie| session::store(&cookie, project))
+        {
+            Ok(source) => {
+                println!("🎄 Stored session cookie in {source}.");
+                // a cookie with higher precedence would shadow the one that was just stored.
+                if let Some(active) = session::load().filter(|active| active.source != source) {
+                    println!("Note: the cookie from {} takes precedence.", active.source);
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to store session cookie: {e}");
+                process::exit(1);
+            }
+        },
+        SessionCommand::Show => match session::load() {
+            Some(session) => println!("{} (from {})", session.masked(), session.source),
+            None => {
+                eprintln!(
+                    "No session cookie found. Run `cargo session set <cookie>` to store one."
+                );
+                process::exit(1);
+            }
+        },
+        SessionCommand::Check => {
+            let result = aoc_client::default_year()
+                .and_then(AocClient::from_env)
+                .and_then(|client| client.check_session());
+
+            match (result, session::load()) {
+                (Ok(()), Some(session)) => {
+                    println!("✔ Session cookie from {} is valid.", session.source);
+                }
+                (Ok(()), None) => println!("✔ Session cookie is valid."),
+                (Err(e), _) => {
+                    eprintln!("✘ {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// Reads a cookie from the first line of stdin, prompting for it in a terminal.
+fn read_cookie() -> io::Result<String> {
+    if io::stdin().is_terminal() {
+        eprint!("Paste your session cookie: ");
+    }
+
+    let mut cookie = String::new();
+    io::stdin().read_line(&mut cookie)?;
+    Ok(cookie)
+}
@@ -1,23 +1,54 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
 
-use crate::Day;
+use crate::template::{
+    runner::{BenchConfig, SubmitConfig},
+    InputSource,
+};
+use crate::{Part, PuzzleId};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    submit: Option<SubmitConfig>,
+    bench: Option<&BenchConfig>,
+    timeout: Option<Duration>,
+    input: &InputSource,
+    part: Option<Part>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    // mirror allocation accounting to the solution binary.
+    if cfg!(feature = "alloc_stats") {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_stats".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+    cmd_args.append(&mut input.to_args());
+
+    if let Some(submit) = submit {
+        cmd_args.append(&mut submit.to_args());
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(format!("{}ns", timeout.as_nanos()));
+    }
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
     }
 
-    if time {
+    if let Some(bench) = bench {
         cmd_args.push("--time".to_string());
+        cmd_args.append(&mut bench.to_args());
     }
 
     let mut cmd = Command::new("cargo")
@@ -27,5 +58,9 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // forward the exit code of the solution, e.g. the verdict of a submission.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
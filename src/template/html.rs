@@ -0,0 +1,279 @@
+/// Converts all `<article>` elements of a page to markdown, separated by a blank line.
+/// Only the handful of elements that occur in puzzle descriptions and submission responses are supported,
+/// everything else is reduced to its text.
+#[must_use]
+pub fn articles_to_markdown(html: &str) -> String {
+    articles(html)
+        .map(to_markdown)
+        .filter(|article| !article.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Iterates over the inner HTML of all `<article>` elements.
+fn articles(html: &str) -> impl Iterator<Item = &str> {
+    let mut rest = html;
+
+    std::iter::from_fn(move || {
+        let start = rest.find("<article")?;
+        let inner_start = start + rest[start..].find('>')? + 1;
+        let inner_end = inner_start + rest[inner_start..].find("</article>")?;
+        let article = &rest[inner_start..inner_end];
+        rest = &rest[inner_end..];
+        Some(article)
+    })
+}
+
+/// Converts a fragment of HTML to markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<String> = vec![];
+    // start of the current inline code span in `out`, and whether it contained emphasis.
+    let mut code: Option<(usize, bool)> = None;
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            push_text(&mut out, &rest[tag_start..], in_pre);
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        let (closing, name) = match tag.strip_prefix('/') {
+            Some(name) => (true, name),
+            None => (false, tag),
+        };
+        let name = name
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => {
+                start_block(&mut out);
+                out.push_str("## ");
+            }
+            ("p" | "h2" | "ul", true) => end_block(&mut out),
+            ("pre", false) => {
+                start_block(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```");
+                end_block(&mut out);
+                in_pre = false;
+            }
+            ("code", false) if !in_pre => code = Some((out.len(), false)),
+            ("code", true) if !in_pre => {
+                if let Some((start, emphasized)) = code.take() {
+                    let content = out.split_off(start);
+                    let wrapped = format!("`{content}`");
+                    if emphasized {
+                        out.push_str(&format!("*{wrapped}*"));
+                    } else {
+                        out.push_str(&wrapped);
+                    }
+                }
+            }
+            ("em", _) => match &mut code {
+                // emphasis cannot be nested in inline code, it is moved outside of it instead.
+                Some((_, emphasized)) => *emphasized = true,
+                None if !in_pre => out.push('*'),
+                None => {}
+            },
+            ("li", false) => {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("- ");
+            }
+            ("a", false) if !in_pre => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) if !in_pre => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    out.trim().to_string()
+}
+
+/// Appends text, collapsing whitespace unless it is preformatted.
+fn push_text(out: &mut String, text: &str, preformatted: bool) {
+    let text = decode_entities(text);
+
+    if preformatted {
+        out.push_str(&text);
+        return;
+    }
+
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !out.is_empty() && !out.ends_with(char::is_whitespace) {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+fn start_block(out: &mut String) {
+    if !out.is_empty() {
+        end_block(out);
+    }
+}
+
+fn end_block(out: &mut String) {
+    let trimmed = out.trim_end_matches([' ', '\n']).len();
+    out.truncate(trimmed);
+    out.push_str("\n\n");
+}
+
+/// Returns the value of a quoted attribute of a tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}="))? + name.len() + 1;
+    let quote = tag[start..].chars().next()?;
+    let value = &tag[start + 1..];
+    let end = value.find(quote)?;
+    Some(decode_entities(&value[..end]))
+}
+
+/// Decodes the named entities used on Advent of Code and numeric character references.
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                _ => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = concat!(
+            "<html><body><main>\n",
+            "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>",
+            "<p>Something is <em>wrong</em> with <a href=\"/2023/about\">global snow</a>.</p>\n",
+            "<p>For example:</p>\n",
+            "<pre><code>1abc2\npqr3stu8vwx\n&lt;a&gt;\n</code></pre>\n",
+            "<ul><li>One</li><li>Two</li></ul>\n",
+            "<p>In this example, the sum is <code><em>142</em></code>.</p>\n",
+            "</article>\n",
+            "<p>Your puzzle answer was <code>42</code>.</p>\n",
+            "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Again.</p></article>\n",
+            "</main></body></html>"
+        );
+
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Something is *wrong* with [global snow](/2023/about).",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "<a>",
+            "```",
+            "",
+            "- One",
+            "- Two",
+            "",
+            "In this example, the sum is *`142`*.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "Again.",
+        ]
+        .join("\n");
+
+        assert_eq!(articles_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn converts_submission_response() {
+        let html = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer. <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+        assert_eq!(
+            articles_to_markdown(html),
+            "That's the right answer! You are one gold star closer. [[Continue to Part Two]](/2023/day/1#part2)"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            to_markdown("a &amp; b &#39;c&#x27; &unknown; &"),
+            "a & b 'c' &unknown; &"
+        );
+    }
+
+    #[test]
+    fn returns_empty_string_without_articles() {
+        assert_eq!(articles_to_markdown("<html><body>404</body></html>"), "");
+    }
+}
@@ -0,0 +1,343 @@
+/// Module that records submissions and their verdicts in `data/YYYY/submissions/DD.jsonl`, one JSON record per line.
+/// The ledger is used to reject answers that are known to be wrong before submitting them.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::Verdict;
+use crate::template::get_data_path;
+use crate::{Part, PuzzleId};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Entry {
+    #[must_use]
+    pub fn new(part: Part, answer: &str, verdict: Verdict) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            part,
+            answer: answer.trim().to_string(),
+            verdict,
+            timestamp,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn to_json(&self) -> String {
+        let record: HashMap<String, JsonValue> = [
+            ("part", JsonValue::Number(f64::from(self.part.into_inner()))),
+            ("answer", JsonValue::String(self.answer.clone())),
+            ("verdict", JsonValue::String(self.verdict.as_str().into())),
+            ("timestamp", JsonValue::Number(self.timestamp as f64)),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+
+        JsonValue::Object(record)
+            .stringify()
+            .expect("entry only contains finite numbers")
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_json(s: &str) -> Option<Self> {
+        let value: JsonValue = s.parse().ok()?;
+        let record: &HashMap<String, JsonValue> = value.get()?;
+        let part: &f64 = record.get("part")?.get()?;
+        let answer: &String = record.get("answer")?.get()?;
+        let verdict: &String = record.get("verdict")?.get()?;
+        let timestamp: &f64 = record.get("timestamp")?.get()?;
+
+        Some(Self {
+            part: Part::new(*part as u8)?,
+            answer: answer.clone(),
+            verdict: verdict.parse().ok()?,
+            timestamp: *timestamp as u64,
+        })
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The part was already solved, holds the correct answer.
+    Solved(String),
+    /// The same answer was submitted before and judged wrong, holds the verdict it got.
+    KnownWrong(Verdict),
+    /// The answer is not lower than an answer that was too high.
+    AboveTooHigh(String),
+    /// The answer is not higher than an answer that was too low.
+    BelowTooLow(String),
+}
+
+impl Rejection {
+    /// The verdict the website would presumably give.
+    #[must_use]
+    pub fn verdict(&self) -> Verdict {
+        match self {
+            Self::Solved(_) => Verdict::AlreadyCompleted,
+            Self::KnownWrong(verdict) => *verdict,
+            Self::AboveTooHigh(_) => Verdict::TooHigh,
+            Self::BelowTooLow(_) => Verdict::TooLow,
+        }
+    }
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "this part was already solved with `{answer}`."),
+            Self::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted and is {verdict}.")
+            }
+            Self::AboveTooHigh(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than `{bound}`, which was too high."
+                )
+            }
+            Self::BelowTooLow(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than `{bound}`, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/// All submissions for a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Reads the ledger for a day, a missing file yields an empty ledger.
+    pub fn load(id: PuzzleId) -> Result<Self, Error> {
+        let path = env::current_dir()?.join(get_path(id));
+
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let entries = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                Entry::from_json(line)
+                    .ok_or_else(|| Error::Parser(format!("line {}: invalid entry", i + 1)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Appends an entry to the ledger file of a day.
+    pub fn append(id: PuzzleId, entry: &Entry) -> Result<(), Error> {
+        let path = env::current_dir()?.join(get_path(id));
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", entry.to_json())?;
+        Ok(())
+    }
+
+    /// Checks an answer against previous submissions of a part.
+    /// Bounds are only applied if both the answer and the bounds are integers.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Rejection> {
+        let answer = answer.trim();
+        let entries = self.entries.iter().filter(|entry| entry.part == part);
+
+        let mut too_high: Option<(i128, &str)> = None;
+        let mut too_low: Option<(i128, &str)> = None;
+
+        for entry in entries {
+            if entry.verdict == Verdict::Correct {
+                return Err(Rejection::Solved(entry.answer.clone()));
+            }
+
+            if entry.verdict.is_wrong() && entry.answer == answer {
+                return Err(Rejection::KnownWrong(entry.verdict));
+            }
+
+            let Ok(value) = entry.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match entry.verdict {
+                Verdict::TooHigh if too_high.is_none_or(|(bound, _)| value < bound) => {
+                    too_high = Some((value, &entry.answer));
+                }
+                Verdict::TooLow if too_low.is_none_or(|(bound, _)| value > bound) => {
+                    too_low = Some((value, &entry.answer));
+                }
+                _ => {}
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        if let Some((bound, bound_str)) = too_high {
+            if value >= bound {
+                return Err(Rejection::AboveTooHigh(bound_str.into()));
+            }
+        }
+
+        if let Some((bound, bound_str)) = too_low {
+            if value <= bound {
+                return Err(Rejection::BelowTooLow(bound_str.into()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[must_use]
+pub fn get_path(id: PuzzleId) -> String {
+    get_data_path("submissions", id, ".jsonl")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Entry, Ledger, Rejection};
+    use crate::template::aoc_client::Verdict;
+    use crate::Part;
+
+    fn ledger(entries: &[(u8, &str, Verdict)]) -> Ledger {
+        Ledger {
+            entries: entries
+                .iter()
+                .map(|(part, answer, verdict)| Entry {
+                    part: Part::new(*part).unwrap(),
+                    answer: (*answer).to_string(),
+                    verdict: *verdict,
+                    timestamp: 1_700_000_000,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let ledger = ledger(&[
+            (1, "42", Verdict::TooLow),
+            (1, "a \"quoted\" answer", Verdict::Incorrect),
+            (2, "1337", Verdict::Correct),
+        ]);
+        let s: String = ledger
+            .entries
+            .iter()
+            .map(|entry| entry.to_json() + "\n")
+            .collect();
+        assert_eq!(Ledger::parse(&s).unwrap(), ledger);
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        assert!(Ledger::parse("{\"part\":1}").is_err());
+        assert!(Ledger::parse("not json").is_err());
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let ledger = ledger(&[
+            (1, "abc", Verdict::Incorrect),
+            (2, "def", Verdict::Incorrect),
+        ]);
+        assert_eq!(
+            ledger.check(Part::One, "abc"),
+            Err(Rejection::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(ledger.check(Part::One, "def"), Ok(()));
+        assert_eq!(ledger.check(Part::One, "ghi"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let ledger = ledger(&[
+            (1, "100", Verdict::TooHigh),
+            (1, "80", Verdict::TooHigh),
+            (1, "10", Verdict::TooLow),
+            (1, "20", Verdict::TooLow),
+            (1, "50", Verdict::PleaseWait),
+        ]);
+        assert_eq!(
+            ledger.check(Part::One, "80"),
+            Err(Rejection::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            ledger.check(Part::One, "90"),
+            Err(Rejection::AboveTooHigh("80".into()))
+        );
+        assert_eq!(
+            ledger.check(Part::One, "15"),
+            Err(Rejection::BelowTooLow("20".into()))
+        );
+        assert_eq!(ledger.check(Part::One, "50"), Ok(()));
+        assert_eq!(ledger.check(Part::One, "not a number"), Ok(()));
+        assert_eq!(ledger.check(Part::Two, "90"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_solved_parts() {
+        let ledger = ledger(&[(1, "42", Verdict::Correct)]);
+        assert_eq!(
+            ledger.check(Part::One, "43"),
+            Err(Rejection::Solved("42".into()))
+        );
+        assert_eq!(ledger.check(Part::Two, "43"), Ok(()));
+    }
+}
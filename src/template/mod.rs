@@ -1,10 +1,20 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Part, PuzzleId};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod html;
+pub mod ledger;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod report;
+pub mod resources;
 pub mod runner;
+pub mod session;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -12,25 +22,217 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let filepath = env::current_dir()
+        .unwrap()
+        .join(get_data_path(folder, puzzle, ".txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the text file of a part to a string, e.g. a separate example for part two in `data/YYYY/examples/DD-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: Part) -> String {
+    let filepath =
+        env::current_dir()
+            .unwrap()
+            .join(get_data_path(folder, puzzle, &format!("-{part}.txt")));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Returns the path of a file for a puzzle in a data folder of its year, e.g. `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, suffix: &str) -> String {
+    format!("data/{}/{folder}/{}{suffix}", puzzle.year, puzzle.day)
+}
+
+/// Returns the path of the solution module of a puzzle, e.g. `src/bin/2023_01.rs`.
+#[must_use]
+pub fn get_module_path(puzzle: PuzzleId) -> String {
+    format!("src/bin/{}.rs", puzzle.bin_name())
+}
+
+/// Formats the arguments that select a puzzle in commands such as `cargo solve`, e.g. `01 --year 2022`.
+/// The year is left out if it is the default year.
+#[must_use]
+pub fn puzzle_args(puzzle: PuzzleId) -> String {
+    if aoc_client::default_year().is_ok_and(|year| year == puzzle.year) {
+        puzzle.day.to_string()
+    } else {
+        format!("{} --year {}", puzzle.day, puzzle.year)
+    }
+}
+
+/// Writes a file, creating its parent directories if they do not exist yet.
+pub fn write_file(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    if let Some(dir) = path.as_ref().parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Source of the input that a solution binary runs against.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The puzzle input in `data/YYYY/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/YYYY/examples` (`--example`).
+    Example,
+    /// An arbitrary file (`--input <path>`).
+    File(PathBuf),
+    /// Input piped to standard input (`--stdin`).
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input source from command-line arguments, at most one of the flags may be passed.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut sources = vec![];
+
+        if let Some(path) = args.opt_value_from_str::<_, PathBuf>("--input")? {
+            sources.push(Self::File(path));
+        }
+
+        if args.contains("--stdin") {
+            sources.push(Self::Stdin);
+        }
+
+        if args.contains("--example") {
+            sources.push(Self::Example);
+        }
+
+        match sources.len() {
+            0 => Ok(Self::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => Err("only one of `--input`, `--stdin` and `--example` can be passed".into()),
+        }
+    }
+
+    /// Converts the input source back into command-line arguments for a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example => vec!["--example".into()],
+            Self::File(path) => vec!["--input".into(), path.to_string_lossy().into()],
+            Self::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        let read_data = |folder| {
+            fs::read_to_string(env::current_dir()?.join(get_data_path(folder, puzzle, ".txt")))
+        };
+
+        match self {
+            Self::Puzzle => read_data("inputs"),
+            Self::Example => read_data("examples"),
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Reads the input source from the command-line arguments of the current process.
+    /// Exits the process if the arguments are invalid.
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self::parse(&mut pico_args::Arguments::from_env()).unwrap_or_else(|e| {
+            eprintln!("Invalid input source: {e}");
+            process::exit(1);
+        })
+    }
+}
+
+/// Reads the input for a solution binary from the source selected on the command-line.
+#[must_use]
+pub fn read_input(puzzle: PuzzleId) -> String {
+    // `--part` is read again by each part, validating it here rejects an invalid value before the parse step runs.
+    runner::selected_part();
+
+    let source = InputSource::from_args();
+    source.read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read input ({source:?}): {e}");
+        process::exit(1);
+    })
+}
+
+/// Creates the constants `PUZZLE` and `DAY` and sets up the input and runner for each part.
+/// The input is read from `data/YYYY/inputs` unless `--example`, `--input <path>` or `--stdin` is passed.
+///
+/// Passing `parse` as third argument (`solution!(2023, 1, parse)`) runs a `parse` function first.
+/// Its output is timed separately and passed by reference to both parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::puzzle!($year, $day);
         /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        #[allow(dead_code)]
+        const DAY: advent_of_code::Day = PUZZLE.day;
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input = advent_of_code::template::read_input(PUZZLE);
+            run_part(part_one, &input, PUZZLE, advent_of_code::Part::One);
+            run_part(part_two, &input, PUZZLE, advent_of_code::Part::Two);
         }
     };
+    ($year:expr, $day:expr, parse) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::puzzle!($year, $day);
+        /// The current day.
+        #[allow(dead_code)]
+        const DAY: advent_of_code::Day = PUZZLE.day;
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = advent_of_code::template::read_input(PUZZLE);
+            if let Some(parsed) = run_parse(parse, input.as_str(), PUZZLE) {
+                run_part(part_one, &parsed, PUZZLE, advent_of_code::Part::One);
+                run_part(part_two, &parsed, PUZZLE, advent_of_code::Part::Two);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn parse(args: &[&str]) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let args = args.iter().map(Into::into).collect();
+        InputSource::parse(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse(&[]).unwrap(), InputSource::Puzzle);
+        assert_eq!(parse(&["--example"]).unwrap(), InputSource::Example);
+        assert_eq!(parse(&["--stdin"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            parse(&["--input", "other.txt"]).unwrap(),
+            InputSource::File("other.txt".into())
+        );
+        assert!(parse(&["--stdin", "--example"]).is_err());
+    }
+
+    #[test]
+    fn input_source_roundtrip() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example,
+            InputSource::Stdin,
+            InputSource::File("data/other.txt".into()),
+        ] {
+            let args: Vec<String> = source.to_args();
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            assert_eq!(parse(&args).unwrap(), source);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+/// Module that extracts examples and their expected answers from puzzle descriptions in `data/YYYY/puzzles/DD.md`.
+/// Examples are the fenced code blocks of a part, expected answers are emphasized inline code such as *`142`*.
+use std::{env, fs, io};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+/// Examples and emphasized values found in the description of one part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// Contents of the code blocks, in order of appearance.
+    pub examples: Vec<String>,
+    /// Emphasized inline code values, in order of appearance.
+    pub emphasized: Vec<String>,
+}
+
+impl PuzzlePart {
+    /// The example input, which is the first code block of the part.
+    #[must_use]
+    pub fn example(&self) -> Option<&str> {
+        self.examples.first().map(String::as_str)
+    }
+
+    /// The expected answer for the example.
+    /// Descriptions emphasize intermediate results before the final one, so this is the last emphasized value.
+    #[must_use]
+    pub fn answer(&self) -> Option<&str> {
+        self.emphasized.last().map(String::as_str)
+    }
+}
+
+/// Parses a puzzle description into its parts, a new part starts at every heading.
+#[must_use]
+pub fn parse(markdown: &str) -> Vec<PuzzlePart> {
+    let mut parts: Vec<PuzzlePart> = vec![];
+    let mut code_block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match code_block.take() {
+                Some(block) => {
+                    if parts.is_empty() {
+                        parts.push(PuzzlePart::default());
+                    }
+                    parts.last_mut().unwrap().examples.push(block);
+                }
+                None => code_block = Some(String::new()),
+            }
+            continue;
+        }
+
+        if let Some(block) = &mut code_block {
+            block.push_str(line);
+            block.push('\n');
+            continue;
+        }
+
+        // text before the first heading belongs to the first part.
+        let is_new_part = line.starts_with("## ") && parts.last() != Some(&PuzzlePart::default());
+        if parts.is_empty() || is_new_part {
+            parts.push(PuzzlePart::default());
+        }
+
+        let part = parts.last_mut().unwrap();
+        part.emphasized.extend(emphasized_code(line));
+    }
+
+    parts
+}
+
+/// Reads and parses the puzzle description of a day.
+pub fn load(id: PuzzleId) -> io::Result<Vec<PuzzlePart>> {
+    let path = env::current_dir()?.join(get_path(id));
+    Ok(parse(&fs::read_to_string(path)?))
+}
+
+#[must_use]
+pub fn get_path(id: PuzzleId) -> String {
+    get_data_path("puzzles", id, ".md")
+}
+
+/// Finds all values formatted as *`value`*.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("*`") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("`*") else {
+            break;
+        };
+        values.push(rest[..end].to_string());
+        rest = &rest[end + 2..];
+    }
+
+    values
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, PuzzlePart};
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are *`12`* and *`38`*. Adding these together produces *`50`*.
+
+## --- Part Two ---
+
+Your calculation isn't quite right.
+
+```
+two1nine
+```
+
+In this example, the calibration value is *`29`*.";
+
+    #[test]
+    fn parses_parts() {
+        let parts = parse(PUZZLE);
+        assert_eq!(
+            parts,
+            vec![
+                PuzzlePart {
+                    examples: vec!["1abc2\npqr3stu8vwx\n".into()],
+                    emphasized: vec!["12".into(), "38".into(), "50".into()],
+                },
+                PuzzlePart {
+                    examples: vec!["two1nine\n".into()],
+                    emphasized: vec!["29".into()],
+                },
+            ]
+        );
+        assert_eq!(parts[0].example(), Some("1abc2\npqr3stu8vwx\n"));
+        assert_eq!(parts[0].answer(), Some("50"));
+    }
+
+    #[test]
+    fn parses_part_without_examples() {
+        let parts = parse("## --- Day 2 ---\n\nNothing to see here, *not code*.");
+        assert_eq!(parts, vec![PuzzlePart::default()]);
+        assert_eq!(parts[0].example(), None);
+        assert_eq!(parts[0].answer(), None);
+    }
+
+    #[test]
+    fn keeps_code_block_contents() {
+        let parts = parse("```\n## not a heading\n*`not emphasized`*\n\n```");
+        assert_eq!(
+            parts[0].examples,
+            vec!["## not a heading\n*`not emphasized`*\n\n".to_string()]
+        );
+        assert!(parts[0].emphasized.is_empty());
+    }
+}
@@ -2,7 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::get_module_path;
+use crate::{PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,9 +21,14 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Highest peak resident memory of all parts.
+    pub peak_rss: Option<String>,
+    /// CPU time of all parts, user and system time combined.
+    pub cpu_time: Option<String>,
     pub total_nanos: f64,
 }
 
@@ -32,8 +38,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./{}", get_module_path(puzzle))
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -58,38 +64,182 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+/// The benchmark table of one year.
+struct YearTable {
+    year: Year,
+    rows: Vec<String>,
+    total_millis: f64,
+}
+
+/// Groups timings into one table per year, in ascending order.
+fn year_tables(timings: &[Timings]) -> Vec<YearTable> {
+    let mut years: Vec<Year> = timings.iter().map(|timing| timing.puzzle.year).collect();
+    years.sort_unstable();
+    years.dedup();
+
+    years
+        .into_iter()
+        .map(|year| {
+            let year_timings: Vec<Timings> = timings
+                .iter()
+                .filter(|timing| timing.puzzle.year == year)
+                .cloned()
+                .collect();
+
+            YearTable {
+                year,
+                total_millis: year_timings.iter().map(|x| x.total_nanos).sum::<f64>()
+                    / 1_000_000_f64,
+                rows: construct_rows(year_timings),
+            }
+        })
+        .collect()
+}
+
+/// Parses the tables of a benchmark section that was written before, so tables of other years can be kept.
+/// The year of a flat table is taken from the links of its rows.
+fn parse_tables(section: &str) -> Vec<YearTable> {
+    let mut tables: Vec<YearTable> = vec![];
+    let mut flat_rows: Vec<String> = vec![];
+    let mut flat_total = 0_f64;
+
+    let parse_total = |line: &str, prefix: &str, suffix: &str| {
+        line.strip_prefix(prefix)?
+            .strip_suffix(suffix)?
+            .parse::<f64>()
+            .ok()
+    };
+
+    for line in section.lines() {
+        if let Some(year) = line.strip_prefix("### ").and_then(|year| year.parse().ok()) {
+            tables.push(YearTable {
+                year,
+                rows: vec![],
+                total_millis: 0_f64,
+            });
+        } else if line.starts_with('|') {
+            match tables.last_mut() {
+                Some(table) => table.rows.push(line.into()),
+                None => flat_rows.push(line.into()),
+            }
+        } else if let Some(total) = parse_total(line, "**Total: ", "ms**") {
+            flat_total = total;
+        } else if let Some(total) = parse_total(line, "*Total: ", "ms*") {
+            if let Some(table) = tables.last_mut() {
+                table.total_millis = total;
+            }
+        }
+    }
+
+    if tables.is_empty() {
+        if let Some(year) = flat_rows.iter().find_map(|row| year_of_row(row)) {
+            tables.push(YearTable {
+                year,
+                rows: flat_rows,
+                total_millis: flat_total,
+            });
+        }
+    }
+
+    tables
+}
+
+/// Reads the year from the link to a solution in a row, e.g. `./src/bin/2023_01.rs`.
+fn year_of_row(row: &str) -> Option<Year> {
+    let (_, link) = row.split_once("](./src/bin/")?;
+    let (year, _) = link.split_once('_')?;
+    year.parse().ok()
+}
+
+fn construct_table(prefix: &str, tables: &[YearTable], total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
 
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    // a single year keeps the flat table, multiple years get a table each.
+    if let [table] = tables {
+        lines.extend(table.rows.iter().cloned());
+        lines.push(String::new());
+    } else {
+        for table in tables {
+            lines.push(format!("{prefix}# {}", table.year));
+            lines.push(String::new());
+            lines.extend(table.rows.iter().cloned());
+            lines.push(String::new());
+            lines.push(format!("*Total: {:.2}ms*", table.total_millis));
+            lines.push(String::new());
+        }
     }
 
-    lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
+fn construct_rows(timings: Vec<Timings>) -> Vec<String> {
+    // only show optional columns if at least one solution has a value for them.
+    let has_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let has_peak_rss = timings.iter().any(|timing| timing.peak_rss.is_some());
+    let has_cpu_time = timings.iter().any(|timing| timing.cpu_time.is_some());
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_peak_rss {
+        columns.push("Peak RSS");
+    }
+    if has_cpu_time {
+        columns.push("CPU");
+    }
+
+    let mut lines: Vec<String> = vec![
+        format!("| {} |", columns.join(" | ")),
+        format!("| {}  |", vec![":---:"; columns.len()].join(" | ")),
+    ];
+
+    let cell = |value: Option<String>| format!("`{}`", value.unwrap_or_else(|| "-".into()));
+
+    for timing in timings {
+        let mut cells = vec![format!(
+            "[Day {}]({})",
+            timing.puzzle.day.into_inner(),
+            get_path_for_bin(timing.puzzle)
+        )];
+        if has_parse {
+            cells.push(cell(timing.parse));
+        }
+        cells.push(cell(timing.part_1));
+        cells.push(cell(timing.part_2));
+        if has_peak_rss {
+            cells.push(cell(timing.peak_rss));
+        }
+        if has_cpu_time {
+            cells.push(cell(timing.cpu_time));
+        }
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    lines
+}
+
+/// Replaces the tables of the years in `timings` and keeps the tables of other years.
 fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+
+    let mut tables = year_tables(&timings);
+    let kept: Vec<YearTable> = parse_tables(&s[positions.pos_start..positions.pos_end])
+        .into_iter()
+        .filter(|kept| tables.iter().all(|table| table.year != kept.year))
+        .collect();
+    let total_millis = total_millis + kept.iter().map(|table| table.total_millis).sum::<f64>();
+
+    tables.extend(kept);
+    tables.sort_by_key(|table| table.year);
+
+    let table = construct_table("##", &tables, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
@@ -105,26 +255,35 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::puzzle;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: puzzle!(2023, 1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                peak_rss: None,
+                cpu_time: None,
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
+                puzzle: puzzle!(2023, 2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                peak_rss: None,
+                cpu_time: None,
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
+                puzzle: puzzle!(2023, 4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                peak_rss: None,
+                cpu_time: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -172,9 +331,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +342,136 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings[0].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | `5ms` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `-` | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_resources() {
+        let mut timings = get_mock_timings();
+        timings[0].peak_rss = Some("2.0 MiB".into());
+        timings[0].cpu_time = Some("31.0ms".into());
+        timings[1].cpu_time = Some("71.0ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 | Peak RSS | CPU |",
+            "| :---: | :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10ms` | `20ms` | `2.0 MiB` | `31.0ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30ms` | `40ms` | `-` | `71.0ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `40ms` | `50ms` | `-` | `-` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_grouped_by_year() {
+        let mut timings = get_mock_timings();
+        timings[2].puzzle = puzzle!(2024, 4);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "### 2023",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30ms` | `40ms` |",
+            "",
+            "*Total: 100000.00ms*",
+            "",
+            "### 2024",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 4](./src/bin/2024_04.rs) | `40ms` | `50ms` |",
+            "",
+            "*Total: 90000.00ms*",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn keeps_benchmarks_of_other_years() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+
+        let mut timings = get_mock_timings();
+        timings.truncate(1);
+        timings[0].puzzle = puzzle!(2024, 4);
+        update_content(&mut s, timings.clone(), 30.0).unwrap();
+
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "### 2023",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `40ms` | `50ms` |",
+            "",
+            "*Total: 190.00ms*",
+            "",
+            "### 2024",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 4](./src/bin/2024_04.rs) | `10ms` | `20ms` |",
+            "",
+            "*Total: 30000.00ms*",
+            "",
+            "**Total: 220.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+
+        // running a year again replaces its table only.
+        timings[0].part_1 = Some("5ms".into());
+        update_content(&mut s, timings, 30.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/2024_04.rs) | `5ms` | `20ms` |"));
+        assert!(s.contains("| [Day 4](./src/bin/2023_04.rs) | `40ms` | `50ms` |"));
+        assert_eq!(s.matches("### 2024").count(), 1);
+        assert!(s.contains("**Total: 220.00ms**"));
+    }
 }
@@ -0,0 +1,481 @@
+/// Machine-readable output of solution binaries.
+/// `all` invokes the binaries with `--format json` and consumes one record per part instead of scraping text.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use tinyjson::JsonValue;
+
+use crate::template::alloc::AllocStats;
+use crate::template::answers::AnswerCheck;
+use crate::template::resources::ResourceUsage;
+use crate::template::runner::Stats;
+use crate::{Day, Part, MAX_DAYS};
+
+/// Output format of a solution binary, selected via `--format <text|json>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the output format from the command-line arguments of the current process.
+    pub fn from_args() -> Result<Self, pico_args::Error> {
+        pico_args::Arguments::from_env()
+            .opt_value_from_str("--format")
+            .map(Option::unwrap_or_default)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text` or `json`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The phase of a solution that a report was created for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The optional parse step whose output is shared by both parts.
+    Parse,
+    Part(Part),
+}
+
+/// Outcome of running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer, or the parse step completed.
+    Solved,
+    /// The part returned [`None`].
+    Unsolved,
+    /// The part panicked.
+    Crashed,
+    /// The part exceeded its timeout.
+    TimedOut,
+}
+
+impl Status {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Unsolved => "unsolved",
+            Self::Crashed => "crashed",
+            Self::TimedOut => "timed_out",
+        }
+    }
+
+    /// Whether the status should be counted as a failure when running all solutions.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Crashed | Self::TimedOut)
+    }
+}
+
+impl FromStr for Status {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Self::Solved),
+            "unsolved" => Ok(Self::Unsolved),
+            "crashed" => Ok(Self::Crashed),
+            "timed_out" => Ok(Self::TimedOut),
+            _ => Err(()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Result and timing information for a single solution part or the parse step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    pub phase: Phase,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Describes why the part failed, e.g. the panic message and location.
+    pub error: Option<String>,
+    pub stats: Stats,
+    /// Heap allocations of the part, only available with the `alloc_stats` feature.
+    pub allocations: Option<AllocStats>,
+    /// Peak memory and CPU time of the part, only available on Linux.
+    pub resources: Option<ResourceUsage>,
+    /// Result of comparing the answer with the confirmed answer in `data/YYYY/answers`.
+    pub check: AnswerCheck,
+    /// The confirmed answer, set if the check failed.
+    pub expected: Option<String>,
+}
+
+impl PartReport {
+    /// Whether the part should be counted as a failure when running all solutions.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.status.is_failure() || self.check == AnswerCheck::Wrong
+    }
+
+    /// Creates a report for a part that panicked after running for `elapsed`.
+    #[must_use]
+    pub fn crashed(day: Day, phase: Phase, error: String, elapsed: Duration) -> Self {
+        Self {
+            day,
+            phase,
+            status: Status::Crashed,
+            answer: None,
+            error: Some(error),
+            stats: Stats::single(elapsed),
+            allocations: None,
+            resources: None,
+            check: AnswerCheck::Unknown,
+            expected: None,
+        }
+    }
+
+    /// Creates a report for a part that was stopped after exceeding `timeout`.
+    #[must_use]
+    pub fn timed_out(day: Day, phase: Phase, timeout: Duration) -> Self {
+        Self {
+            day,
+            phase,
+            status: Status::TimedOut,
+            answer: None,
+            error: Some(format!("exceeded timeout of {timeout:.1?}")),
+            stats: Stats::single(timeout),
+            allocations: None,
+            resources: None,
+            check: AnswerCheck::Unknown,
+            expected: None,
+        }
+    }
+
+    /// Serializes the report as a single line of JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let stats = &self.stats;
+
+        let duration = object([
+            ("min_ns", nanos(stats.min)),
+            ("median_ns", nanos(stats.median)),
+            ("p95_ns", nanos(stats.p95)),
+            ("max_ns", nanos(stats.max)),
+            ("std_dev_ns", nanos(stats.std_dev)),
+            ("mad_ns", nanos(stats.mad)),
+            ("outliers", number(stats.outliers as u64)),
+        ]);
+
+        let allocations = match &self.allocations {
+            Some(allocations) => object([
+                ("count", number(allocations.count as u64)),
+                ("bytes", number(allocations.bytes as u64)),
+                ("peak_bytes", number(allocations.peak_bytes as u64)),
+            ]),
+            None => JsonValue::Null,
+        };
+
+        let resources = match &self.resources {
+            Some(resources) => object([
+                ("peak_rss_bytes", number(resources.peak_rss_bytes as u64)),
+                ("user_ns", nanos(resources.user_time)),
+                ("system_ns", nanos(resources.system_time)),
+            ]),
+            None => JsonValue::Null,
+        };
+
+        let answer = optional_string(self.answer.as_ref());
+        let error = optional_string(self.error.as_ref());
+        let expected = optional_string(self.expected.as_ref());
+
+        let (phase, part) = match self.phase {
+            Phase::Parse => ("parse", JsonValue::Null),
+            Phase::Part(part) => ("part", number(part.into_inner().into())),
+        };
+
+        object([
+            ("day", number(self.day.into_inner().into())),
+            ("phase", JsonValue::String(phase.into())),
+            ("part", part),
+            ("status", JsonValue::String(self.status.as_str().into())),
+            ("answer", answer),
+            ("error", error),
+            ("samples", number(stats.samples as u64)),
+            ("duration", duration),
+            ("allocations", allocations),
+            ("resources", resources),
+            ("check", JsonValue::String(self.check.as_str().into())),
+            ("expected", expected),
+        ])
+        .stringify()
+        .expect("report only contains finite numbers")
+    }
+
+    /// Parses a report from a line of JSON, returns [`None`] if the line is not a valid report.
+    #[must_use]
+    pub fn from_json(s: &str) -> Option<Self> {
+        let value: JsonValue = s.trim().parse().ok()?;
+        let record: &HashMap<String, JsonValue> = value.get()?;
+        let duration: &HashMap<String, JsonValue> = record.get("duration")?.get()?;
+
+        let allocations = match record.get("allocations") {
+            Some(JsonValue::Object(allocations)) => Some(AllocStats {
+                count: get_u64(allocations, "count")?.try_into().ok()?,
+                bytes: get_u64(allocations, "bytes")?.try_into().ok()?,
+                peak_bytes: get_u64(allocations, "peak_bytes")?.try_into().ok()?,
+            }),
+            _ => None,
+        };
+
+        let resources = match record.get("resources") {
+            Some(JsonValue::Object(resources)) => Some(ResourceUsage {
+                peak_rss_bytes: get_u64(resources, "peak_rss_bytes")?.try_into().ok()?,
+                user_time: get_duration(resources, "user_ns")?,
+                system_time: get_duration(resources, "system_ns")?,
+            }),
+            _ => None,
+        };
+
+        let answer = get_optional_string(record, "answer")?;
+        let error = get_optional_string(record, "error")?;
+        let expected = get_optional_string(record, "expected")?;
+        let check: &String = record.get("check")?.get()?;
+
+        let status: &String = record.get("status")?.get()?;
+        let phase: &String = record.get("phase")?.get()?;
+
+        let phase = match phase.as_str() {
+            "parse" => Phase::Parse,
+            "part" => Phase::Part(Part::new(get_u64(record, "part")?.try_into().ok()?)?),
+            _ => return None,
+        };
+
+        Some(Self {
+            // the report does not know its year, the binary already validated the day against it.
+            day: Day::new_in_year(get_u64(record, "day")?.try_into().ok()?, MAX_DAYS)?,
+            phase,
+            status: status.parse().ok()?,
+            answer,
+            error,
+            stats: Stats {
+                samples: get_u64(record, "samples")?.try_into().ok()?,
+                min: get_duration(duration, "min_ns")?,
+                median: get_duration(duration, "median_ns")?,
+                p95: get_duration(duration, "p95_ns")?,
+                max: get_duration(duration, "max_ns")?,
+                std_dev: get_duration(duration, "std_dev_ns")?,
+                mad: get_duration(duration, "mad_ns")?,
+                outliers: get_u64(duration, "outliers")?.try_into().ok()?,
+            },
+            allocations,
+            resources,
+            check: check.parse().ok()?,
+            expected,
+        })
+    }
+}
+
+fn object<const N: usize>(entries: [(&str, JsonValue); N]) -> JsonValue {
+    JsonValue::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn optional_string(s: Option<&String>) -> JsonValue {
+    match s {
+        Some(s) => JsonValue::String(s.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn number(n: u64) -> JsonValue {
+    JsonValue::Number(n as f64)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_u64(record: &HashMap<String, JsonValue>, key: &str) -> Option<u64> {
+    let n: &f64 = record.get(key)?.get()?;
+    (n.is_finite() && *n >= 0.0).then_some(*n as u64)
+}
+
+/// Returns `Some(None)` for `null` values and [`None`] if the key is missing or not a string.
+fn get_optional_string(record: &HashMap<String, JsonValue>, key: &str) -> Option<Option<String>> {
+    match record.get(key)? {
+        JsonValue::String(s) => Some(Some(s.clone())),
+        JsonValue::Null => Some(None),
+        _ => None,
+    }
+}
+
+fn get_duration(record: &HashMap<String, JsonValue>, key: &str) -> Option<Duration> {
+    get_u64(record, key).map(Duration::from_nanos)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{OutputFormat, PartReport, Phase, Status};
+    use crate::{
+        day,
+        template::{
+            alloc::AllocStats, answers::AnswerCheck, resources::ResourceUsage, runner::Stats,
+        },
+        Part,
+    };
+    use std::time::Duration;
+
+    fn get_mock_report() -> PartReport {
+        PartReport {
+            day: day!(3),
+            phase: Phase::Part(Part::Two),
+            status: Status::Solved,
+            answer: Some("multi\n\"line\"".into()),
+            error: None,
+            stats: Stats {
+                samples: 100,
+                min: Duration::from_nanos(10),
+                median: Duration::from_nanos(20),
+                p95: Duration::from_nanos(30),
+                max: Duration::from_nanos(40),
+                std_dev: Duration::from_nanos(5),
+                mad: Duration::from_nanos(2),
+                outliers: 3,
+            },
+            allocations: Some(AllocStats {
+                count: 12,
+                bytes: 4096,
+                peak_bytes: 1024,
+            }),
+            resources: Some(ResourceUsage {
+                peak_rss_bytes: 2 * 1024 * 1024,
+                user_time: Duration::from_micros(150),
+                system_time: Duration::from_micros(20),
+            }),
+            check: AnswerCheck::Wrong,
+            expected: Some("42".into()),
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let report = get_mock_report();
+        let json = report.to_json();
+        assert!(!json.contains('\n'));
+        assert_eq!(PartReport::from_json(&json), Some(report));
+    }
+
+    #[test]
+    fn roundtrip_unsolved() {
+        let report = PartReport {
+            status: Status::Unsolved,
+            answer: None,
+            allocations: None,
+            resources: None,
+            ..get_mock_report()
+        };
+        assert_eq!(PartReport::from_json(&report.to_json()), Some(report));
+    }
+
+    #[test]
+    fn roundtrip_parse() {
+        let report = PartReport {
+            phase: Phase::Parse,
+            answer: None,
+            ..get_mock_report()
+        };
+        assert_eq!(PartReport::from_json(&report.to_json()), Some(report));
+    }
+
+    #[test]
+    fn roundtrip_crashed() {
+        let report = PartReport::crashed(
+            day!(3),
+            Phase::Part(Part::One),
+            "panicked at src/bin/03.rs:1:1: boom".into(),
+            Duration::from_millis(1),
+        );
+        assert_eq!(PartReport::from_json(&report.to_json()), Some(report));
+    }
+
+    #[test]
+    fn roundtrip_timed_out() {
+        let report =
+            PartReport::timed_out(day!(3), Phase::Part(Part::Two), Duration::from_secs(10));
+        assert_eq!(report.error.as_deref(), Some("exceeded timeout of 10.0s"));
+        assert_eq!(PartReport::from_json(&report.to_json()), Some(report));
+    }
+
+    #[test]
+    fn roundtrip_days_of_any_year() {
+        // day 25 does not exist in 12 day events, the report is still valid for earlier years.
+        let report = PartReport {
+            day: day!(25),
+            ..get_mock_report()
+        };
+        assert_eq!(PartReport::from_json(&report.to_json()), Some(report));
+
+        let json = get_mock_report()
+            .to_json()
+            .replace("\"day\":3", "\"day\":26");
+        assert_eq!(PartReport::from_json(&json), None);
+    }
+
+    #[test]
+    fn failures() {
+        let report = get_mock_report();
+        assert!(report.is_failure());
+
+        let report = PartReport {
+            check: AnswerCheck::Correct,
+            expected: None,
+            ..get_mock_report()
+        };
+        assert!(!report.is_failure());
+    }
+
+    #[test]
+    fn rejects_other_lines() {
+        assert_eq!(PartReport::from_json("Part 1: 42 (10.0ns)"), None);
+        assert_eq!(PartReport::from_json("{\"day\": 1}"), None);
+        assert_eq!(PartReport::from_json(""), None);
+    }
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -0,0 +1,144 @@
+/// Process resource accounting for solution parts.
+/// Peak resident memory is read from `/proc/self/status` and CPU time from `getrusage`, both are only available on Linux.
+use std::time::Duration;
+
+/// Resources used by the process while running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceUsage {
+    /// Highest resident set size of the process while the part ran, including memory that was resident before.
+    pub peak_rss_bytes: usize,
+    /// CPU time spent in user mode, summed over all threads.
+    pub user_time: Duration,
+    /// CPU time spent in kernel mode, summed over all threads.
+    pub system_time: Duration,
+}
+
+impl ResourceUsage {
+    /// Total CPU time spent in user and kernel mode.
+    #[must_use]
+    pub fn cpu_time(&self) -> Duration {
+        self.user_time + self.system_time
+    }
+}
+
+/// Runs `func` and returns the resources it used.
+/// Returns [`None`] for the resources on platforms other than Linux, or if they could not be read.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<ResourceUsage>) {
+    #[cfg(target_os = "linux")]
+    {
+        linux::reset_peak_rss();
+        let start = linux::cpu_times();
+
+        let result = func();
+
+        let end = linux::cpu_times();
+
+        let usage =
+            start
+                .zip(end)
+                .and_then(|((user_start, system_start), (user_end, system_end))| {
+                    Some(ResourceUsage {
+                        peak_rss_bytes: linux::peak_rss_bytes()?,
+                        user_time: user_end.saturating_sub(user_start),
+                        system_time: system_end.saturating_sub(system_start),
+                    })
+                });
+
+        (result, usage)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        (func(), None)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::{fs, mem::MaybeUninit, time::Duration};
+
+    /// Resets the peak resident set size of the process to its current resident set size.
+    /// If this is not permitted, the peak covers the whole lifetime of the process instead.
+    pub fn reset_peak_rss() {
+        let _ = fs::write("/proc/self/clear_refs", "5");
+    }
+
+    /// Reads the peak resident set size (`VmHWM`) of the process.
+    pub fn peak_rss_bytes() -> Option<usize> {
+        let status = fs::read_to_string("/proc/self/status").ok()?;
+        parse_peak_rss(&status)
+    }
+
+    pub(super) fn parse_peak_rss(status: &str) -> Option<usize> {
+        let kib: usize = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmHWM:"))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse()
+            .ok()?;
+
+        Some(kib * 1024)
+    }
+
+    /// Returns the user and system CPU time consumed by the process so far.
+    pub fn cpu_times() -> Option<(Duration, Duration)> {
+        let mut usage = MaybeUninit::<libc::rusage>::zeroed();
+
+        // SAFETY: `getrusage` only writes to the passed struct, which is initialized on success.
+        let usage = unsafe {
+            if libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) != 0 {
+                return None;
+            }
+            usage.assume_init()
+        };
+
+        Some((to_duration(usage.ru_utime), to_duration(usage.ru_stime)))
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn to_duration(time: libc::timeval) -> Duration {
+        Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{measure, ResourceUsage};
+    use std::time::Duration;
+
+    #[test]
+    fn totals_cpu_time() {
+        let usage = ResourceUsage {
+            peak_rss_bytes: 0,
+            user_time: Duration::from_millis(3),
+            system_time: Duration::from_millis(1),
+        };
+        assert_eq!(usage.cpu_time(), Duration::from_millis(4));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parses_peak_rss() {
+        let status = "Name:\tcat\nVmPeak:\t    8000 kB\nVmHWM:\t    1536 kB\nVmRSS:\t    1024 kB\n";
+        assert_eq!(super::linux::parse_peak_rss(status), Some(1536 * 1024));
+        assert_eq!(super::linux::parse_peak_rss("Name:\tcat\n"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn measures_resources() {
+        let (len, usage) = measure(|| {
+            vec![1_u8; 8 * 1024 * 1024]
+                .iter()
+                .filter(|x| **x == 1)
+                .count()
+        });
+        let usage = usage.unwrap();
+        assert_eq!(len, 8 * 1024 * 1024);
+        assert!(usage.peak_rss_bytes >= 8 * 1024 * 1024);
+    }
+}
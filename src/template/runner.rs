@@ -1,63 +1,620 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::alloc::{self, format_bytes, AllocStats};
+use crate::template::answers::{self, AnswerCheck, Answers};
+use crate::template::aoc_client::{self, Verdict};
+use crate::template::commands::example;
+use crate::template::ledger::{self, Entry, Ledger};
+use crate::template::puzzle;
+use crate::template::puzzle_args;
+use crate::template::report::{OutputFormat, PartReport, Phase, Status};
+use crate::template::resources::{self, ResourceUsage};
+use crate::template::{InputSource, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, Part, PuzzleId};
+use std::cell::Cell;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::hint::black_box;
+use std::io::{self, stdout, IsTerminal, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Run the parse step of a solution and return its output, which is then shared by both parts.
+/// Returns [`None`] if the parse step panicked.
+pub fn run_parse<I: Clone, T>(func: impl Fn(I) -> T, input: I, puzzle: PuzzleId) -> Option<T> {
+    let format = output_format();
+    let timer = Instant::now();
+    let watchdog = Cell::new(arm_watchdog(format, puzzle.day, Phase::Parse));
+
+    let outcome = catch_panic(|| match format {
+        OutputFormat::Text => run_timed(
+            func,
+            input,
+            |_| {
+                watchdog.take();
+                print!("Parse:");
+            },
+            true,
+        ),
+        OutputFormat::Json => run_timed(func, input, |_| drop(watchdog.take()), false),
+    });
+
+    watchdog.take();
+
+    let (parsed, report) = match outcome {
+        Ok((parsed, stats, allocations, resources)) => (
+            Some(parsed),
+            PartReport {
+                day: puzzle.day,
+                phase: Phase::Parse,
+                status: Status::Solved,
+                answer: None,
+                error: None,
+                stats,
+                allocations,
+                resources,
+                check: AnswerCheck::Unknown,
+                expected: None,
+            },
+        ),
+        Err(error) => (
+            None,
+            PartReport::crashed(puzzle.day, Phase::Parse, error, timer.elapsed()),
+        ),
+    };
+
+    emit_report(format, &report);
+
+    parsed
+}
+
+/// Return types that are supported for solution parts.
+/// Both [`None`] and [`Err`] mark a part as unsolved, errors are printed along with it.
+pub trait PartResult {
+    type Answer: Display;
+
+    /// Returns the answer, or the error message if there is one.
+    fn answer(&self) -> Result<&Self::Answer, Option<String>>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<&T, Option<String>> {
+        self.as_ref().ok_or(None)
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<&T, Option<String>> {
+        self.as_ref().map_err(|e| Some(e.to_string()))
+    }
+}
+
+pub fn run_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: Part,
+) {
+    if selected_part().is_some_and(|selected| selected != part) {
+        return;
+    }
+
+    let format = output_format();
+
+    // there is nothing to solve for the second star of the last day, so it is not reported.
+    if !part.has_puzzle(puzzle) {
+        if format == OutputFormat::Text {
+            println!("Part {part}: {ANSI_ITALIC}no puzzle, the last star is awarded for all others{ANSI_RESET}");
+        }
+        return;
+    }
+
+    let timer = Instant::now();
+    let watchdog = Cell::new(arm_watchdog(format, puzzle.day, Phase::Part(part)));
+
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let outcome = catch_panic(|| match format {
+        OutputFormat::Text => run_timed(
+            func,
+            input,
+            |result| {
+                watchdog.take();
+                print_result(result.answer(), &part_str, "");
+            },
+            true,
+        ),
+        OutputFormat::Json => run_timed(func, input, |_| drop(watchdog.take()), false),
+    });
+
+    watchdog.take();
+
+    let (result, mut report) = match outcome {
+        Ok((result, stats, allocations, resources)) => {
+            let answer = result.answer();
+            let report = PartReport {
+                day: puzzle.day,
+                phase: Phase::Part(part),
+                status: if answer.is_ok() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                answer: answer.as_ref().ok().map(ToString::to_string),
+                error: answer.err().flatten(),
+                stats,
+                allocations,
+                resources,
+                check: AnswerCheck::Unknown,
+                expected: None,
+            };
+            (Some(result), report)
+        }
+        Err(error) => (
+            None,
+            PartReport::crashed(puzzle.day, Phase::Part(part), error, timer.elapsed()),
+        ),
+    };
+
+    // stored answers only apply to the puzzle input.
+    let answers = if InputSource::from_args() == InputSource::Puzzle {
+        Answers::load(puzzle).unwrap_or_else(|e| {
+            eprintln!("Could not read {}: {e}", answers::get_path(puzzle));
+            Answers::default()
+        })
+    } else {
+        Answers::default()
+    };
+
+    report.check = answers.check(part, report.answer.as_deref());
+    if report.check == AnswerCheck::Wrong {
+        report.expected = answers.get(part).map(ToString::to_string);
+    }
+
+    emit_report(format, &report);
+
+    if let Some(Ok(answer)) = result.as_ref().map(PartResult::answer) {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// Prints the final result line of a part, followed by its timing statistics if it was benched.
+pub fn print_report(report: &PartReport) {
+    let mut duration_str = match report.check {
+        AnswerCheck::Correct => " ✔".to_string(),
+        AnswerCheck::Wrong if report.answer.is_some() => format!(
+            " ✘ {ANSI_BOLD}wrong{ANSI_RESET}, expected {}",
+            report.expected.as_deref().unwrap_or("-")
+        ),
+        _ => String::new(),
+    };
+
+    duration_str.push_str(&format_duration(&report.stats));
+
+    if let Some(allocations) = &report.allocations {
+        duration_str.push_str(&format_allocations(allocations));
+    }
+
+    if let Some(resources) = &report.resources {
+        duration_str.push_str(&format_resources(resources));
+    }
+
+    let label = match report.phase {
+        Phase::Parse => "Parse".to_string(),
+        Phase::Part(part) => format!("Part {part}"),
+    };
+
+    match (report.phase, report.status) {
+        (_, Status::Crashed | Status::TimedOut) => {
+            let status = if report.status == Status::Crashed {
+                "crashed"
+            } else {
+                "timed out"
+            };
+            print!("\r");
+            println!(
+                "{label}: ✖ {ANSI_BOLD}{status}{ANSI_RESET} {ANSI_ITALIC}({}){ANSI_RESET}",
+                report.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+        (Phase::Parse, _) => {
+            print!("\r");
+            println!("{label}:{duration_str}");
+        }
+        (Phase::Part(_), _) => {
+            print_result(
+                report.answer.as_ref().ok_or(report.error.clone()),
+                &label,
+                &duration_str,
+            );
+
+            if report.answer.is_none() && report.check == AnswerCheck::Wrong {
+                println!(
+                    "  ✘ {ANSI_BOLD}wrong{ANSI_RESET}, expected {}",
+                    report.expected.as_deref().unwrap_or("-")
+                );
+            }
+        }
+    }
+
+    if report.status == Status::Solved && report.stats.samples > 1 {
+        print_stats(&report.stats);
+    }
+}
+
+fn emit_report(format: OutputFormat, report: &PartReport) {
+    match format {
+        OutputFormat::Text => print_report(report),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+}
+
+/// Exit code of a solution binary that was stopped by the watchdog, the same as `timeout(1)` uses.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Starts a watchdog if a timeout was configured via `--timeout` or `AOC_TIMEOUT`.
+/// If the watchdog is not dropped before the timeout passes, the phase is reported as timed out and the process exits,
+/// since a running solution cannot be interrupted.
+fn arm_watchdog(format: OutputFormat, day: Day, phase: Phase) -> Option<mpsc::Sender<()>> {
+    let timeout = match parse_timeout(&mut pico_args::Arguments::from_env()) {
+        Ok(timeout) => timeout?,
+        Err(e) => {
+            eprintln!("Invalid timeout: {e}");
+            process::exit(1);
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel::<()>();
+
+    thread::spawn(move || {
+        // dropping the sender disconnects the channel, which disarms the watchdog.
+        if receiver.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+            emit_report(format, &PartReport::timed_out(day, phase, timeout));
+            process::exit(TIMEOUT_EXIT_CODE);
+        }
+    });
+
+    Some(sender)
+}
+
+/// Returns the part selected via `--part`, or [`None`] if both parts should run.
+pub fn selected_part() -> Option<Part> {
+    pico_args::Arguments::from_env()
+        .opt_value_from_str("--part")
+        .unwrap_or_else(|e| {
+            eprintln!("Invalid part: {e}");
+            process::exit(1);
+        })
+}
+
+/// Reads the per-part timeout from the `--timeout` flag or the `AOC_TIMEOUT` environment variable.
+pub fn parse_timeout(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+    arg_or_env(args, "--timeout", "AOC_TIMEOUT", parse_duration)
+}
+
+/// Runs `func`, catching unwinding panics.
+/// The default panic message is suppressed, instead the message and location are returned as error.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    let location: Arc<Mutex<Option<String>>> = Arc::default();
+    let hook_location = Arc::clone(&location);
+
+    let thread_id = thread::current().id();
+
+    let default_hook = Arc::new(panic::take_hook());
+    let hook_default = Arc::clone(&default_hook);
+    panic::set_hook(Box::new(move |info| {
+        // panics of other threads, e.g. threads spawned by the solution, are reported as usual.
+        if thread::current().id() != thread_id {
+            hook_default(info);
+            return;
+        }
+
+        if let (Some(l), Ok(mut location)) = (info.location(), hook_location.lock()) {
+            *location = Some(l.to_string());
+        }
+    }));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+
+    // dropping the temporary hook releases its reference to the default hook.
+    drop(panic::take_hook());
+    match Arc::try_unwrap(default_hook) {
+        Ok(hook) => panic::set_hook(hook),
+        Err(hook) => panic::set_hook(Box::new(move |info| hook(info))),
+    }
+
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".into());
+
+        match location.lock().ok().and_then(|mut l| l.take()) {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        }
+    })
+}
+
+fn output_format() -> OutputFormat {
+    match OutputFormat::from_args() {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("Invalid output format: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Summary statistics over the execution times collected for a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    /// Median absolute deviation, a spread measure that is robust against outliers.
+    pub mad: Duration,
+    /// Number of samples outside of the interquartile fences (`1.5 * IQR`).
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Creates [`Stats`] for a single, un-benched execution.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            min: duration,
+            median: duration,
+            p95: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            mad: Duration::ZERO,
+            outliers: 0,
+        }
+    }
+
+    /// Computes [`Stats`] from a non-empty list of samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let iqr = q3.saturating_sub(q1);
+        let fence = iqr.mul_f64(1.5);
+        let lower = q1.saturating_sub(fence);
+        let upper = q3.saturating_add(fence);
+
+        let median = percentile(&sorted, 50.0);
+
+        let mut deviations: Vec<Duration> = sorted.iter().map(|d| d.abs_diff(median)).collect();
+        deviations.sort_unstable();
+
+        Self {
+            samples: sorted.len(),
+            min: sorted[0],
+            median,
+            p95: percentile(&sorted, 95.0),
+            max: sorted[sorted.len() - 1],
+            std_dev: std_dev(&sorted),
+            mad: percentile(&deviations, 50.0),
+            outliers: sorted.iter().filter(|&&d| d < lower || d > upper).count(),
+        }
+    }
+}
+
+/// Controls how a solution part is benched when running with `--time`.
+///
+/// Every value can be set via command-line flag or environment variable, flags take precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate execution time to spend on collecting samples (`--budget`, `AOC_BENCH_BUDGET`).
+    pub budget: Duration,
+    /// Execution time to spend on warming up before collecting samples (`--warmup`, `AOC_BENCH_WARMUP`).
+    pub warmup: Duration,
+    /// Lower bound for the number of samples (`--min-samples`, `AOC_BENCH_MIN_SAMPLES`).
+    pub min_samples: u32,
+    /// Upper bound for the number of samples (`--max-samples`, `AOC_BENCH_MAX_SAMPLES`).
+    pub max_samples: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            warmup: Duration::ZERO,
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the bench configuration from the environment and the passed command-line arguments.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut config = Self::default();
+
+        if let Some(budget) = arg_or_env(args, "--budget", "AOC_BENCH_BUDGET", parse_duration)? {
+            config.budget = budget;
+        }
+
+        if let Some(warmup) = arg_or_env(args, "--warmup", "AOC_BENCH_WARMUP", parse_duration)? {
+            config.warmup = warmup;
+        }
+
+        if let Some(min) = arg_or_env(args, "--min-samples", "AOC_BENCH_MIN_SAMPLES", str::parse)? {
+            config.min_samples = min;
+        }
+
+        if let Some(max) = arg_or_env(args, "--max-samples", "AOC_BENCH_MAX_SAMPLES", str::parse)? {
+            config.max_samples = max;
+        }
+
+        if config.min_samples == 0 || config.min_samples > config.max_samples {
+            return Err(format!(
+                "invalid sample bounds: expecting 1 <= min-samples ({}) <= max-samples ({})",
+                config.min_samples, config.max_samples
+            )
+            .into());
+        }
+
+        Ok(config)
+    }
+
+    /// Converts the configuration back into command-line arguments for a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--budget".into(),
+            format!("{}ns", self.budget.as_nanos()),
+            "--warmup".into(),
+            format!("{}ns", self.warmup.as_nanos()),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    /// Number of samples to collect for a function that took `base_time` on its first execution.
+    fn iterations(&self, base_time: &Duration) -> u128 {
+        (self.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+            .clamp(u128::from(self.min_samples), u128::from(self.max_samples))
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Reads a value from the command-line flag `flag`, falling back to the environment variable `var`.
+pub fn arg_or_env<T, E: Display>(
+    args: &mut pico_args::Arguments,
+    flag: &'static str,
+    var: &str,
+    parser: fn(&str) -> Result<T, E>,
+) -> Result<Option<T>, Box<dyn std::error::Error>> {
+    if let Some(value) = args.opt_value_from_fn(flag, parser)? {
+        return Ok(Some(value));
+    }
+
+    match env::var(var) {
+        Ok(value) => parser(&value)
+            .map(Some)
+            .map_err(|e| format!("invalid value for {var}: {e}").into()),
+        Err(_) => Ok(None),
     }
 }
 
+/// An error which can be returned when parsing a duration with [`parse_duration`].
+#[derive(Debug)]
+pub struct ParseDurationError;
+
+impl std::error::Error for ParseDurationError {}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a duration with unit, e.g. `500ms`, `2s` or `1.5s` (units: ns, us, µs, ms, s)")
+    }
+}
+
+/// Parses a human-readable duration such as `250ms` or `1.5s`.
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or(ParseDurationError)?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().map_err(|_| ParseDurationError)?;
+
+    let seconds = match unit {
+        "ns" => value / 1_000_000_000_f64,
+        "us" | "µs" => value / 1_000_000_f64,
+        "ms" => value / 1000_f64,
+        "s" => value,
+        _ => return Err(ParseDurationError),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| ParseDurationError)
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched according to the [`BenchConfig`] (by default approx. 1 second of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+    show_progress: bool,
+) -> (T, Stats, Option<AllocStats>, Option<ResourceUsage>) {
+    let cloned = input.clone();
+    // allocations and resources are only measured for the first execution, the benchmark would repeat them.
+    let (((result, base_time), allocations), resources) = resources::measure(|| {
+        alloc::measure(|| {
+            let timer = Instant::now();
+            let result = func(cloned);
+            (result, timer.elapsed())
+        })
+    });
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if std::env::args().any(|x| x == "--time") {
+        let config = match BenchConfig::parse(&mut pico_args::Arguments::from_env()) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("\nInvalid bench configuration: {e}");
+                process::exit(1);
+            }
+        };
+        bench(func, input, &base_time, &config, show_progress)
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats, allocations, resources)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    show_progress: bool,
+) -> Stats {
     let mut stdout = stdout();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    if !config.warmup.is_zero() {
+        if show_progress {
+            print!(" > {ANSI_ITALIC}warming up{ANSI_RESET}");
+            let _ = stdout.flush();
+        }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+        let timer = Instant::now();
+        while timer.elapsed() < config.warmup {
+            black_box(func(black_box(input.clone())));
+        }
+    }
+
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
+
+    let bench_iterations = config.iterations(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -65,38 +622,78 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
-        func(cloned);
+        // `black_box` keeps the compiler from optimizing away the call or its result.
+        let result = black_box(func(black_box(cloned)));
         timers.push(timer.elapsed());
+        drop(result);
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn std_dev(samples: &[Duration]) -> Duration {
+    let n = samples.len() as f64;
+    let mean = samples.iter().map(Duration::as_nanos).sum::<u128>() as f64 / n;
+    let variance = samples
         .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+        .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    Duration::from_secs_f64(variance.sqrt() / 1_000_000_000_f64)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples)",
+            stats.median, stats.mad, stats.samples
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_allocations(allocations: &AllocStats) -> String {
+    format!(
+        " [{} allocs · {} · peak {}]",
+        allocations.count,
+        format_bytes(allocations.bytes),
+        format_bytes(allocations.peak_bytes)
+    )
+}
+
+fn format_resources(resources: &ResourceUsage) -> String {
+    format!(
+        " [RSS {} · CPU {:.1?}]",
+        format_bytes(resources.peak_rss_bytes),
+        resources.cpu_time()
+    )
+}
+
+fn print_stats(stats: &Stats) {
+    println!(
+        "  {ANSI_ITALIC}min {:.1?} · p95 {:.1?} · max {:.1?} · σ {:.1?} · {} outlier(s){ANSI_RESET}",
+        stats.min, stats.p95, stats.max, stats.std_dev, stats.outliers
+    );
+}
+
+fn print_result<T: Display>(result: Result<&T, Option<String>>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -116,52 +713,395 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Err(error) => {
+            let error_str = error
+                .map(|e| format!(" {ANSI_ITALIC}({e}){ANSI_RESET}"))
+                .unwrap_or_default();
+
             if is_intermediate_result {
-                print!("{part}: ✖");
+                print!("{part}: ✖{error_str}");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{error_str}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Submission of a part, configured via `--submit <part>` and `--wait`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmitConfig {
+    pub part: Part,
+    /// Wait out the rate limit and retry if an answer was submitted too recently.
+    pub wait: bool,
+}
+
+impl SubmitConfig {
+    /// Reads the submission from arguments, returns [`None`] if nothing should be submitted.
+    pub fn parse(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let part = args.opt_value_from_str("--submit")?;
+        let wait = args.contains("--wait");
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+        match part {
+            Some(part) => Ok(Some(Self { part, wait })),
+            None if wait => Err("`--wait` can only be used together with `--submit <part>`".into()),
+            None => Ok(None),
+        }
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    /// Serializes the submission to arguments that [`SubmitConfig::parse`] accepts.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec!["--submit".to_string(), self.part.to_string()];
+        if self.wait {
+            args.push("--wait".into());
+        }
+        args
+    }
+}
+
+/// Shows a countdown on a single line and returns once `duration` has passed.
+pub fn countdown(label: &str, duration: Duration) {
+    let deadline = Instant::now() + duration;
+    let mut stdout = stdout();
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        print!(
+            "\r{label} {ANSI_ITALIC}{}{ANSI_RESET}   ",
+            format_remaining(remaining)
+        );
+        let _ = stdout.flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    println!("\r{label} {ANSI_ITALIC}0s{ANSI_RESET}   ");
+}
+
+/// Formats a remaining time in whole seconds, e.g. `1h 2m 5s`.
+#[must_use]
+pub fn format_remaining(remaining: Duration) -> String {
+    // round up, so the countdown never shows 0s while waiting.
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, _) => format!("{minutes}m {seconds}s"),
+        _ => format!("{hours}h {minutes}m {seconds}s"),
+    }
+}
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+///  1. `--submit <part>` matches the part that was just run.
+///  2. the result was computed from the puzzle input.
+///  3. the submission ledger does not rule the result out.
+///
+/// If the answer was submitted too recently, it is retried after the rate limit with `--wait`.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: Part) {
+    let config = match SubmitConfig::parse(&mut pico_args::Arguments::from_env()) {
+        Ok(Some(config)) if config.part == part => config,
+        Ok(_) => return,
+        Err(e) => {
+            eprintln!("Unexpected command-line input: {e}. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
+    };
+
+    if InputSource::from_args() != InputSource::Puzzle {
+        eprintln!("Refusing to submit a result that was not computed from the puzzle input.");
         process::exit(1);
+    }
+
+    let answer = result.to_string();
+
+    let submission = loop {
+        // the ledger is checked before every attempt, so answers judged wrong in the meantime are never retried.
+        let ledger = Ledger::load(puzzle).unwrap_or_else(|e| {
+            eprintln!("Could not read {}: {e}", ledger::get_path(puzzle));
+            process::exit(1);
+        });
+
+        if let Err(rejection) = ledger.check(part, &answer) {
+            eprintln!("Not submitting part {part}: {rejection}");
+            process::exit(rejection.verdict().exit_code());
+        }
+
+        println!("Submitting result for part {part}...");
+
+        let submission = match aoc_client::submit(puzzle, part, &answer) {
+            Ok(submission) => submission,
+            Err(e) => {
+                eprintln!("Failed to submit result: {e}");
+                process::exit(1);
+            }
+        };
+
+        if let Err(e) = Ledger::append(puzzle, &Entry::new(part, &answer, submission.verdict)) {
+            eprintln!(
+                "Could not record submission in {}: {e}",
+                ledger::get_path(puzzle)
+            );
+        }
+
+        let symbol = match submission.verdict {
+            Verdict::Correct => "✔",
+            verdict if verdict.is_wrong() => "✘",
+            _ => "!",
+        };
+
+        println!(
+            "{symbol} {ANSI_BOLD}Part {part}: {}{ANSI_RESET}",
+            submission.verdict
+        );
+        println!("{ANSI_ITALIC}{}{ANSI_RESET}", submission.message);
+
+        match (submission.verdict, submission.wait_time()) {
+            (Verdict::PleaseWait, Some(wait)) if config.wait => {
+                // a second of leeway, so the retry does not race the rate limit.
+                countdown("Retrying in", wait + Duration::from_secs(1));
+            }
+            (Verdict::PleaseWait, Some(wait)) => {
+                println!(
+                    "You can submit again in {}, pass `--wait` to retry automatically.",
+                    format_remaining(wait)
+                );
+                break submission;
+            }
+            _ => break submission,
+        }
     };
 
-    if part_submit != part {
-        return None;
+    // the remaining part is skipped, so scripts get the exit code of the verdict.
+    if submission.verdict != Verdict::Correct {
+        process::exit(submission.verdict.exit_code());
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if part == Part::One {
+        update_puzzle(puzzle);
+    }
+}
+
+/// Fetches the puzzle description again after part one was solved, since it now contains part two.
+/// Offers to extract the example of part two if one was found.
+fn update_puzzle(puzzle: PuzzleId) {
+    println!("Fetching part two of the puzzle...");
+
+    let parts = match aoc_client::update_puzzle(puzzle) {
+        Ok(markdown) => puzzle::parse(&markdown),
+        Err(e) => {
+            eprintln!("Could not update {}: {e}", puzzle::get_path(puzzle));
+            return;
+        }
+    };
+
+    println!("🎄 Updated \"{}\".", puzzle::get_path(puzzle));
+
+    let Some(part_two) = parts.get(1) else {
+        return;
+    };
+
+    if part_two.example().is_none() && part_two.answer().is_none() {
+        return;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!();
+    example::print_part(Part::Two, part_two);
+
+    if confirm("Extract the example of part 2?") {
+        example::write_part(puzzle, Part::Two, &parts, false);
+    } else {
+        println!(
+            "🎄 Type `cargo example {} --write` to extract it later.",
+            puzzle_args(puzzle)
+        );
+    }
+}
+
+/// Asks a yes/no question on the terminal, defaulting to yes.
+/// Returns `false` without asking if stdin is not a terminal.
+fn confirm(question: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+
+    print!("{question} [Y/n] ");
+    let _ = stdout().flush();
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+
+    matches!(
+        answer.trim().to_ascii_lowercase().as_str(),
+        "" | "y" | "yes"
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        catch_panic, format_remaining, format_resources, parse_duration, BenchConfig, PartResult,
+        Stats, SubmitConfig,
+    };
+    use crate::template::resources::ResourceUsage;
+    use crate::Part;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let stats = Stats::from_samples(&nanos(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.mad, Duration::from_nanos(1));
+        assert_eq!(stats.outliers, 0);
+        // population standard deviation of 1..=5 is sqrt(2).
+        assert_eq!(stats.std_dev.as_nanos(), 1);
+    }
+
+    #[test]
+    fn stats_detect_outliers() {
+        let mut samples = vec![10; 19];
+        samples.push(1000);
+        let stats = Stats::from_samples(&nanos(&samples));
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(stats.p95, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+        assert_eq!(stats.mad, Duration::ZERO);
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("20us").unwrap(), Duration::from_micros(20));
+        assert_eq!(parse_duration("20µs").unwrap(), Duration::from_micros(20));
+        assert_eq!(parse_duration("7ns").unwrap(), Duration::from_nanos(7));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("10h").is_err());
+    }
+
+    #[test]
+    fn formats_remaining_time() {
+        assert_eq!(format_remaining(Duration::from_millis(36_200)), "37s");
+        assert_eq!(format_remaining(Duration::from_secs(65)), "1m 5s");
+        assert_eq!(
+            format_remaining(Duration::from_secs(3 * 3600 + 5)),
+            "3h 0m 5s"
+        );
+    }
+
+    #[test]
+    fn submit_config_roundtrip() {
+        let parse = |args: Vec<String>| {
+            let args = args.into_iter().map(Into::into).collect();
+            SubmitConfig::parse(&mut pico_args::Arguments::from_vec(args))
+        };
+
+        for config in [
+            SubmitConfig {
+                part: Part::One,
+                wait: false,
+            },
+            SubmitConfig {
+                part: Part::Two,
+                wait: true,
+            },
+        ] {
+            assert_eq!(parse(config.to_args()).unwrap(), Some(config));
+        }
+
+        assert_eq!(parse(vec![]).unwrap(), None);
+        assert!(parse(vec!["--wait".into()]).is_err());
+        assert!(parse(vec!["--submit".into(), "3".into()]).is_err());
+    }
+
+    #[test]
+    fn bench_config_iterations() {
+        let config = BenchConfig {
+            budget: Duration::from_millis(10),
+            warmup: Duration::ZERO,
+            min_samples: 5,
+            max_samples: 50,
+        };
+        assert_eq!(config.iterations(&Duration::from_millis(1)), 10);
+        assert_eq!(config.iterations(&Duration::from_millis(100)), 5);
+        assert_eq!(config.iterations(&Duration::from_nanos(1)), 50);
+    }
+
+    #[test]
+    fn bench_config_roundtrip() {
+        let config = BenchConfig {
+            budget: Duration::from_millis(250),
+            warmup: Duration::from_millis(50),
+            min_samples: 3,
+            max_samples: 30,
+        };
+        let args = config.to_args().into_iter().map(Into::into).collect();
+        let parsed = BenchConfig::parse(&mut pico_args::Arguments::from_vec(args)).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn bench_config_rejects_bad_bounds() {
+        let args = ["--min-samples", "20", "--max-samples", "10"]
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert!(BenchConfig::parse(&mut pico_args::Arguments::from_vec(args)).is_err());
+    }
+
+    #[test]
+    fn part_results() {
+        assert_eq!(Some(42).answer(), Ok(&42));
+        assert_eq!(None::<u32>.answer(), Err(None));
+        assert_eq!(Ok::<u32, String>(42).answer(), Ok(&42));
+        assert_eq!(
+            Err::<u32, &str>("no path found").answer(),
+            Err(Some("no path found".to_string()))
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let error = catch_panic(|| -> u32 { panic!("boom {}", 1) }).unwrap_err();
+        assert!(error.starts_with("panicked at src/template/runner.rs:"));
+        assert!(error.ends_with(": boom 1"));
+    }
+
+    #[test]
+    fn stats_single() {
+        let stats = Stats::single(Duration::from_millis(2));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, Duration::from_millis(2));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn formats_resources() {
+        let resources = ResourceUsage {
+            peak_rss_bytes: 3 * 1024 * 1024,
+            user_time: Duration::from_millis(12),
+            system_time: Duration::from_millis(3),
+        };
+        assert_eq!(format_resources(&resources), " [RSS 3.0 MiB · CPU 15.0ms]");
+    }
 }
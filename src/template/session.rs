@@ -0,0 +1,160 @@
+/// Module that locates and stores the Advent of Code session cookie.
+/// The cookie is read from the first of these sources that is set:
+///  1. the `AOC_SESSION` environment variable.
+///  2. an `.adventofcode.session` file in the project root.
+///  3. an `.adventofcode.session` file in the home directory.
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Where a session cookie was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionSource {
+    Env,
+    Project(PathBuf),
+    Home(PathBuf),
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionSource::Env => write!(f, "environment variable {SESSION_VAR}"),
+            SessionSource::Project(path) => write!(f, "project file {path:?}"),
+            SessionSource::Home(path) => write!(f, "home file {path:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub cookie: String,
+    pub source: SessionSource,
+}
+
+impl Session {
+    /// Returns the cookie with all but its first and last four characters hidden.
+    #[must_use]
+    pub fn masked(&self) -> String {
+        let chars: Vec<char> = self.cookie.chars().collect();
+
+        if chars.len() <= 8 {
+            return "*".repeat(chars.len());
+        }
+
+        let start: String = chars[..4].iter().collect();
+        let end: String = chars[chars.len() - 4..].iter().collect();
+        format!("{start}…{end}")
+    }
+}
+
+/// Reads the session cookie from the source with the highest precedence.
+/// Returns [`None`] if no source is set.
+#[must_use]
+pub fn load() -> Option<Session> {
+    if let Some(cookie) = env::var(SESSION_VAR).ok().and_then(|s| normalize(&s)) {
+        return Some(Session {
+            cookie,
+            source: SessionSource::Env,
+        });
+    }
+
+    [project_source(), home_source()]
+        .into_iter()
+        .flatten()
+        .find_map(|source| {
+            let path = match &source {
+                SessionSource::Project(path) | SessionSource::Home(path) => path,
+                SessionSource::Env => return None,
+            };
+            let cookie = normalize(&fs::read_to_string(path).ok()?)?;
+            Some(Session { cookie, source })
+        })
+}
+
+/// Writes a session cookie to the project or home file and returns the source it can be read from.
+pub fn store(cookie: &str, project: bool) -> io::Result<SessionSource> {
+    let cookie = normalize(cookie).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "the session cookie is empty")
+    })?;
+
+    let source = if project {
+        project_source()
+    } else {
+        home_source()
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "could not locate the directory"))?;
+
+    if let SessionSource::Project(path) | SessionSource::Home(path) = &source {
+        write_private(path, &format!("{cookie}\n"))?;
+    }
+
+    Ok(source)
+}
+
+/// Writes a file that only the current user can read, as the cookie grants access to the account.
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        // the mode only applies to new files, an existing file keeps its permissions otherwise.
+        options.mode(0o600);
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+fn project_source() -> Option<SessionSource> {
+    let dir = env::current_dir().ok()?;
+    Some(SessionSource::Project(dir.join(SESSION_FILE)))
+}
+
+fn home_source() -> Option<SessionSource> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(SessionSource::Home(PathBuf::from(home).join(SESSION_FILE)))
+}
+
+/// Trims a cookie and strips a `session=` prefix, as copied from the browser's request headers.
+fn normalize(cookie: &str) -> Option<String> {
+    let cookie = cookie.trim();
+    let cookie = cookie.strip_prefix("session=").unwrap_or(cookie).trim();
+    (!cookie.is_empty()).then(|| cookie.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{normalize, Session, SessionSource};
+
+    #[test]
+    fn normalizes_cookies() {
+        assert_eq!(normalize("  abc123\n"), Some("abc123".into()));
+        assert_eq!(normalize("session=abc123"), Some("abc123".into()));
+        assert_eq!(normalize(" \n"), None);
+        assert_eq!(normalize("session="), None);
+    }
+
+    #[test]
+    fn masks_cookies() {
+        let session = |cookie: &str| Session {
+            cookie: cookie.into(),
+            source: SessionSource::Env,
+        };
+        assert_eq!(session("0123456789abcdef").masked(), "0123…cdef");
+        assert_eq!(session("short").masked(), "*****");
+    }
+}